@@ -0,0 +1,414 @@
+//! Generated by capsule
+//!
+//! `main.rs` is used to define rust lang items and modules.
+
+#![no_std]
+#![no_main]
+#![feature(lang_items)]
+#![feature(alloc_error_handler)]
+#![feature(panic_info_message)]
+#![allow(unused_imports)]
+#![allow(unused_variables)]
+#![allow(dead_code)]
+
+// Import from `core` instead of from `std` since we are in no-std mode.
+use core::convert::TryInto;
+use core::option::Option;
+use core::result::Result;
+
+// Import heap related library from `alloc` since we are in no-std mode.
+// https://doc.rust-lang.org/alloc/index.html
+use alloc::{collections::btree_set::BTreeSet, vec, vec::Vec};
+
+// Import Blake2b functionality.
+use blake2b_ref::Blake2bBuilder;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::{debug, default_alloc, entry};
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, packed::Bytes as Args, packed::OutPoint, prelude::*};
+use ckb_std::error::{SysError};
+use ckb_std::high_level::{load_cell_data, load_cell_lock_hash, load_cell_type_hash, load_input, load_script, load_script_hash, load_witness_args, QueryIter};
+
+// Import the generated Molecule bindings for Allowlist data.
+mod schemas;
+use schemas::allowlist::{AllowlistClaimReader, AllowlistClaimVecReader, AllowlistInfoReader};
+
+// Constants
+const BLAKE2B256_HASH_LEN: usize = 32; // Number of bytes for a Blake2b-256 hash.
+const ALLOWLIST_ID_LEN: usize = BLAKE2B256_HASH_LEN; // Number of bytes in the Allowlist ID.
+const LOCK_HASH_LEN: usize = BLAKE2B256_HASH_LEN; // Number of bytes for a lock hash. (Blake2b 32 bytes)
+const U32_LEN: usize = 4; // Number of bytes for a 32-bit unsigned integer.
+const U128_LEN: usize = 16; // Number of bytes for a 128-bit unsigned integer.
+const ARGS_LEN: usize = LOCK_HASH_LEN + ALLOWLIST_ID_LEN; // Number of bytes required for args. (64 bytes)
+
+entry!(program_entry);
+default_alloc!();
+
+/// Program entry point.
+fn program_entry() -> i8
+{
+	// Call main function and return error code.
+	match main()
+	{
+		Ok(_) => 0,
+		Err(err) => i8::from(err),
+	}
+}
+
+/// Local error values.
+/// Low values are reserved for Sys Error codes.
+/// Values 10-49 are for custom errors codes.
+enum Error
+{
+	IndexOutOfBound,
+	ItemMissing,
+	LengthNotEnough,
+	Encoding,
+	InvalidAllowlistId,
+	InvalidArgsLen,
+	InvalidClaim,
+	InvalidClaimedBitmap,
+	InvalidProof,
+	UnauthorizedOperation,
+	UnexpectedCellCount,
+}
+
+impl From<Error> for i8
+{
+	fn from(err: Error) -> Self
+	{
+		match err
+		{
+			Error::IndexOutOfBound => 1,
+			Error::ItemMissing => 2,
+			Error::LengthNotEnough => 3,
+			Error::Encoding => 4,
+			Error::InvalidAllowlistId => 10,
+			Error::InvalidArgsLen => 11,
+			Error::InvalidClaim => 12,
+			Error::InvalidClaimedBitmap => 13,
+			Error::InvalidProof => 14,
+			Error::UnauthorizedOperation => 15,
+			Error::UnexpectedCellCount => 16,
+		}
+	}
+}
+
+/// Map Sys Errors to local Error values.
+impl From<SysError> for Error
+{
+	fn from(err: SysError) -> Self
+	{
+		use SysError::*;
+		match err
+		{
+			IndexOutOfBound => Self::IndexOutOfBound,
+			ItemMissing => Self::ItemMissing,
+			LengthNotEnough(_) => Self::LengthNotEnough,
+			Encoding => Self::Encoding,
+			Unknown(err_code) => panic!("Unexpected Sys Error: {}", err_code),
+		}
+	}
+}
+
+/// Holds the parsed values of the script args.
+#[derive(Debug)]
+struct AllowlistArgs
+{
+	governance_lock_hash: Vec<u8>,
+	allowlist_id: Vec<u8>,
+}
+
+/// Holds the parsed values of an Allowlist Cell data field.
+#[derive(Debug)]
+struct AllowlistInfo
+{
+	root: Vec<u8>,
+	claimed: Vec<u8>,
+}
+
+/// Holds the parsed values of a single allowlist claim.
+#[derive(Debug)]
+struct AllowlistClaim
+{
+	index: u32,
+	lock_hash: [u8; LOCK_HASH_LEN],
+	quantity: u128,
+	proof: Vec<[u8; BLAKE2B256_HASH_LEN]>,
+}
+
+/// Parse the script args into an AllowlistArgs instance.
+/// Layout: Governance Lock Hash | Allowlist ID
+fn parse_args(args: &Args) -> Result<AllowlistArgs, Error>
+{
+	let args: Bytes = args.unpack();
+
+	// Verify that the exact length of the arguments was given.
+	if args.len() != ARGS_LEN
+	{
+		return Err(Error::InvalidArgsLen);
+	}
+
+	let allowlist_args = AllowlistArgs
+	{
+		governance_lock_hash: args[0..LOCK_HASH_LEN].to_vec(),
+		allowlist_id: args[LOCK_HASH_LEN..ARGS_LEN].to_vec(),
+	};
+
+	Ok(allowlist_args)
+}
+
+/// Determine if owner mode is enabled.
+fn check_owner_mode(args: &AllowlistArgs) -> Result<bool, Error>
+{
+	// Compares the governance Lock Script Hash from the args with the Lock Scripts
+	// of all input Cells to determine if a match exists.
+	let is_owner_mode = QueryIter::new(load_cell_lock_hash, Source::Input)
+		.find(|lock_hash| args.governance_lock_hash[..] == lock_hash[..]).is_some();
+
+	Ok(is_owner_mode)
+}
+
+/// Calculates an Allowlist ID from the first input and the output index of the Allowlist Cell.
+/// This uses the same method as an NFT Instance ID, which guarantees the Allowlist ID is unique.
+fn calculate_allowlist_id(seed_cell_outpoint: &OutPoint, output_index: usize) -> [u8; BLAKE2B256_HASH_LEN]
+{
+	let mut blake2b = Blake2bBuilder::new(BLAKE2B256_HASH_LEN).personal(b"ckb-default-hash").build();
+
+	blake2b.update(&seed_cell_outpoint.tx_hash().raw_data());
+	blake2b.update(&seed_cell_outpoint.index().raw_data());
+	blake2b.update(&(output_index as u32).to_le_bytes());
+
+	let mut hash: [u8; BLAKE2B256_HASH_LEN] = [0; BLAKE2B256_HASH_LEN];
+	blake2b.finalize(&mut hash);
+
+	hash
+}
+
+/// Locate the index of the first Cell in the output with the current type script.
+fn locate_group_output_index() -> Result<usize, Error>
+{
+	let script_hash = load_script_hash()?;
+	let index = QueryIter::new(load_cell_type_hash, Source::Output)
+		.position(|type_hash| type_hash == Some(script_hash))
+		.ok_or(Error::ItemMissing)?;
+
+	Ok(index)
+}
+
+/// Load and validate the data of an Allowlist Cell.
+fn load_allowlist_info(index: usize, source: Source) -> Result<AllowlistInfo, Error>
+{
+	let cell_data = load_cell_data(index, source)?;
+	let allowlist_info = AllowlistInfoReader::from_slice(&cell_data).or(Err(Error::Encoding))?;
+
+	let allowlist_info = AllowlistInfo
+	{
+		root: allowlist_info.root().raw_data().to_vec(),
+		claimed: allowlist_info.claimed().raw_data().to_vec(),
+	};
+
+	Ok(allowlist_info)
+}
+
+/// Load the claims from the input type field of the witness of the Allowlist Cell. No claims are made when it is omitted.
+fn load_allowlist_claims() -> Result<Vec<AllowlistClaim>, Error>
+{
+	let claims = match load_witness_args(0, Source::GroupInput)
+	{
+		Ok(witness_args) => witness_args.input_type().to_opt(),
+		Err(SysError::IndexOutOfBound) => None,
+		Err(err) => return Err(err.into()),
+	};
+	let claims: Bytes = match claims
+	{
+		Some(claims) => claims.unpack(),
+		None => return Ok(vec!()),
+	};
+
+	let claims = AllowlistClaimVecReader::from_slice(&claims).or(Err(Error::Encoding))?;
+	let mut allowlist_claims = Vec::new();
+	for claim in claims.iter()
+	{
+		allowlist_claims.push(parse_allowlist_claim(&claim)?);
+	}
+
+	Ok(allowlist_claims)
+}
+
+/// Parse a single allowlist claim from the Molecule reader.
+fn parse_allowlist_claim(claim: &AllowlistClaimReader) -> Result<AllowlistClaim, Error>
+{
+	let index: [u8; U32_LEN] = claim.index().raw_data().try_into().or(Err(Error::Encoding))?;
+	let lock_hash: [u8; LOCK_HASH_LEN] = claim.lock_hash().raw_data().try_into().or(Err(Error::Encoding))?;
+	let quantity: [u8; U128_LEN] = claim.quantity().raw_data().try_into().or(Err(Error::Encoding))?;
+
+	let mut proof = Vec::new();
+	for hash in claim.proof().iter()
+	{
+		proof.push(hash.raw_data().try_into().or(Err(Error::Encoding))?);
+	}
+
+	let allowlist_claim = AllowlistClaim
+	{
+		index: u32::from_le_bytes(index),
+		lock_hash: lock_hash,
+		quantity: u128::from_le_bytes(quantity),
+		proof: proof,
+	};
+
+	Ok(allowlist_claim)
+}
+
+/// Calculates the Merkle root of the allowlist from a claim and its proof.
+fn calculate_allowlist_root(claim: &AllowlistClaim) -> [u8; BLAKE2B256_HASH_LEN]
+{
+	// The leaf commits to the index, the lock hash of the minter, and the allowed quantity.
+	let mut blake2b = Blake2bBuilder::new(BLAKE2B256_HASH_LEN).personal(b"ckb-default-hash").build();
+	blake2b.update(&claim.index.to_le_bytes());
+	blake2b.update(&claim.lock_hash);
+	blake2b.update(&claim.quantity.to_le_bytes());
+
+	let mut hash: [u8; BLAKE2B256_HASH_LEN] = [0; BLAKE2B256_HASH_LEN];
+	blake2b.finalize(&mut hash);
+
+	// Each bit of the index selects if the sibling is on the right or the left at that level.
+	for (level, sibling) in claim.proof.iter().enumerate()
+	{
+		let mut blake2b = Blake2bBuilder::new(BLAKE2B256_HASH_LEN).personal(b"ckb-default-hash").build();
+		if level < 32 && (claim.index >> level) & 1 == 1
+		{
+			blake2b.update(sibling);
+			blake2b.update(&hash);
+		}
+		else
+		{
+			blake2b.update(&hash);
+			blake2b.update(sibling);
+		}
+		blake2b.finalize(&mut hash);
+	}
+
+	hash
+}
+
+/// Determine if the bit for the specified leaf index is set in a claimed bitmap.
+fn is_claimed(claimed: &Vec<u8>, index: u32) -> bool
+{
+	let index = index as usize;
+	index / 8 < claimed.len() && claimed[index / 8] & (1 << (index % 8)) != 0
+}
+
+fn main() -> Result<(), Error>
+{
+	// Load and parse arguments from the current script.
+	let script = load_script()?;
+	let args = parse_args(&script.args())?;
+
+	// Detect owner mode.
+	let owner_mode = check_owner_mode(&args)?;
+	// debug!("Owner Mode: {}", owner_mode);
+
+	// Count the Allowlist Cells in the group input and group output.
+	let group_input_count = QueryIter::new(load_cell_data, Source::GroupInput).count();
+	let group_output_count = QueryIter::new(load_cell_data, Source::GroupOutput).count();
+
+	// An Allowlist ID can only exist in a single Cell.
+	if group_input_count > 1 || group_output_count > 1
+	{
+		return Err(Error::UnexpectedCellCount);
+	}
+
+	// All changes other than claims, including creation and destruction, are made by the owner.
+	if owner_mode
+	{
+		// Destruction of an Allowlist Cell has no further requirements.
+		if group_output_count == 0
+		{
+			return Ok(());
+		}
+
+		// Validate the structure of the output data.
+		let output_allowlist_info = load_allowlist_info(0, Source::GroupOutput)?;
+
+		if group_input_count == 0
+		{
+			// debug!("Operation: Create");
+
+			// The Allowlist ID must be derived from the Seed Cell to ensure it is unique.
+			let seed_cell_outpoint = load_input(0, Source::Input)?.previous_output();
+			let allowlist_id = calculate_allowlist_id(&seed_cell_outpoint, locate_group_output_index()?);
+			if args.allowlist_id[..] != allowlist_id[..]
+			{
+				return Err(Error::InvalidAllowlistId);
+			}
+
+			// A new allowlist starts with no leaves claimed.
+			if output_allowlist_info.claimed.iter().any(|x| *x != 0)
+			{
+				return Err(Error::InvalidClaimedBitmap);
+			}
+		}
+
+		return Ok(());
+	}
+
+	// debug!("Operation: Claim");
+
+	// Claims can only be made on an existing Allowlist Cell.
+	if group_input_count == 0 || group_output_count == 0
+	{
+		return Err(Error::UnauthorizedOperation);
+	}
+
+	// The Merkle root and the size of the claimed bitmap can only be changed by the owner.
+	let input_allowlist_info = load_allowlist_info(0, Source::GroupInput)?;
+	let output_allowlist_info = load_allowlist_info(0, Source::GroupOutput)?;
+	if input_allowlist_info.root != output_allowlist_info.root
+	{
+		return Err(Error::UnauthorizedOperation);
+	}
+	if input_allowlist_info.claimed.len() != output_allowlist_info.claimed.len()
+	{
+		return Err(Error::InvalidClaimedBitmap);
+	}
+
+	// Claimed leaves can never be unclaimed.
+	let is_unclaimed = input_allowlist_info.claimed.iter().zip(output_allowlist_info.claimed.iter()).any(|(input, output)| input & !output != 0);
+	if is_unclaimed
+	{
+		return Err(Error::InvalidClaimedBitmap);
+	}
+
+	// Every claim must be for a leaf of the allowlist which is newly claimed in this transaction by the minter.
+	let input_lock_hashes: BTreeSet<[u8; LOCK_HASH_LEN]> = QueryIter::new(load_cell_lock_hash, Source::Input).collect();
+	let mut claimed_indexes = BTreeSet::new();
+	for claim in load_allowlist_claims()?.iter()
+	{
+		if is_claimed(&input_allowlist_info.claimed, claim.index) || !is_claimed(&output_allowlist_info.claimed, claim.index) || !claimed_indexes.insert(claim.index)
+		{
+			return Err(Error::InvalidClaim);
+		}
+
+		if calculate_allowlist_root(claim)[..] != input_allowlist_info.root[..]
+		{
+			return Err(Error::InvalidProof);
+		}
+
+		if !input_lock_hashes.contains(&claim.lock_hash)
+		{
+			return Err(Error::UnauthorizedOperation);
+		}
+	}
+
+	// Every newly claimed leaf must have a claim.
+	let newly_claimed_count: u32 = input_allowlist_info.claimed.iter().zip(output_allowlist_info.claimed.iter()).map(|(input, output)| (output & !input).count_ones()).sum();
+	if newly_claimed_count as usize != claimed_indexes.len()
+	{
+		return Err(Error::InvalidClaimedBitmap);
+	}
+
+	Ok(())
+}
@@ -0,0 +1,3 @@
+//! Generated Molecule bindings. See `schemas/` in the repository root for the source schemas.
+
+pub mod allowlist;
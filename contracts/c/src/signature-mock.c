@@ -0,0 +1,42 @@
+#include "stdio.h"
+
+#define MESSAGE_LEN 32
+#define PUBLIC_KEY_LEN 33
+#define SIGNATURE_LEN 65
+
+// A mock signature library for testing the signature governance mode. It is not secure and must never be deployed.
+// It implements the interface of ckb-lib-secp256k1, which is the library that is deployed on chain.
+// A mock signature is: Compressed Public Key (33) | Message (32)
+__attribute__((visibility("default"))) int32_t load_prefilled_data(uint8_t* data, uint64_t* len)
+{
+	(void)data;
+	*len = 0;
+
+	return 0;
+}
+
+__attribute__((visibility("default"))) int32_t validate_signature(const uint8_t* prefilled_data, const uint8_t* signature, uint64_t signature_len, const uint8_t* message, uint64_t message_len, uint8_t* output, uint64_t* output_len)
+{
+	(void)prefilled_data;
+
+	if(signature_len != SIGNATURE_LEN || message_len != MESSAGE_LEN || *output_len < PUBLIC_KEY_LEN)
+	{
+		return 1;
+	}
+
+	for(int i = 0; i < MESSAGE_LEN; i++)
+	{
+		if(signature[PUBLIC_KEY_LEN + i] != message[i])
+		{
+			return 1;
+		}
+	}
+
+	for(int i = 0; i < PUBLIC_KEY_LEN; i++)
+	{
+		output[i] = signature[i];
+	}
+	*output_len = PUBLIC_KEY_LEN;
+
+	return 0;
+}
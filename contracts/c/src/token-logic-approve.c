@@ -4,3 +4,8 @@ __attribute__((visibility("default"))) int32_t token_logic(const char* token_log
 {
 	return 0;
 }
+
+__attribute__((visibility("default"))) int32_t token_logic_mint(const char* token_logic_code_hash)
+{
+	return 0;
+}
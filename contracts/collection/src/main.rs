@@ -0,0 +1,314 @@
+//! Generated by capsule
+//!
+//! `main.rs` is used to define rust lang items and modules.
+
+#![no_std]
+#![no_main]
+#![feature(lang_items)]
+#![feature(alloc_error_handler)]
+#![feature(panic_info_message)]
+#![allow(unused_imports)]
+#![allow(unused_variables)]
+#![allow(dead_code)]
+
+// Import from `core` instead of from `std` since we are in no-std mode.
+use core::convert::TryInto;
+use core::option::Option;
+use core::result::Result;
+
+// Import heap related library from `alloc` since we are in no-std mode.
+// https://doc.rust-lang.org/alloc/index.html
+use alloc::{vec, vec::Vec};
+
+// Import Blake2b functionality.
+use blake2b_ref::Blake2bBuilder;
+
+// Import CKB syscalls and structures.
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::{debug, default_alloc, entry};
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::{bytes::Bytes, packed::Bytes as Args, packed::OutPoint, prelude::*};
+use ckb_std::error::{SysError};
+use ckb_std::high_level::{load_cell_data, load_cell_lock_hash, load_cell_type, load_cell_type_hash, load_input, load_script, load_script_hash, QueryIter};
+
+// Import the generated Molecule bindings for Collection data.
+mod schemas;
+use schemas::collection::CollectionInfoReader;
+
+// Constants
+const BLAKE2B256_HASH_LEN: usize = 32; // Number of bytes for a Blake2b-256 hash.
+const COLLECTION_ID_LEN: usize = BLAKE2B256_HASH_LEN; // Number of bytes in the Collection ID.
+const LOCK_HASH_LEN: usize = BLAKE2B256_HASH_LEN; // Number of bytes for a lock hash. (Blake2b 32 bytes)
+const U128_LEN: usize = 16; // Number of bytes for a 128-bit unsigned integer.
+const NFT_CODE_HASH_LEN: usize = BLAKE2B256_HASH_LEN; // Number of bytes for the code hash of the NFT type script.
+const NFT_HASH_TYPE_LEN: usize = 1; // Number of bytes for the hash type of the NFT type script.
+const ARGS_LEN: usize = LOCK_HASH_LEN + COLLECTION_ID_LEN + NFT_CODE_HASH_LEN + NFT_HASH_TYPE_LEN; // Number of bytes required for args. (97 bytes)
+const NFT_ARGS_FLAGS_OFFSET: usize = LOCK_HASH_LEN; // Offset of the Flags field in the NFT args, following the Governance Hash.
+const NFT_ARGS_FLAGS_LEN: usize = 1; // Number of bytes in the Flags field of the NFT args.
+const NFT_ARGS_EXTENDED_FLAGS_LEN: usize = 1; // Number of bytes in the Extended Flags field of the NFT args.
+const NFT_ARGS_FLAG_COLLECTION: u8 = 0b0001_0000; // The NFT args include a Collection Type Hash.
+const NFT_ARGS_FLAG_EXTENDED: u8 = 0b1000_0000; // The NFT args include an Extended Flags field before the Collection Type Hash.
+
+entry!(program_entry);
+default_alloc!();
+
+/// Program entry point.
+fn program_entry() -> i8
+{
+	// Call main function and return error code.
+	match main()
+	{
+		Ok(_) => 0,
+		Err(err) => i8::from(err),
+	}
+}
+
+/// Local error values.
+/// Low values are reserved for Sys Error codes.
+/// Values 10-49 are for custom errors codes.
+enum Error
+{
+	IndexOutOfBound,
+	ItemMissing,
+	LengthNotEnough,
+	Encoding,
+	InvalidArgsLen,
+	InvalidCollectionId,
+	InvalidMintedCount,
+	MissingNftCell,
+	UnauthorizedOperation,
+	UnexpectedCellCount,
+}
+
+impl From<Error> for i8
+{
+	fn from(err: Error) -> Self
+	{
+		match err
+		{
+			Error::IndexOutOfBound => 1,
+			Error::ItemMissing => 2,
+			Error::LengthNotEnough => 3,
+			Error::Encoding => 4,
+			Error::InvalidArgsLen => 10,
+			Error::InvalidCollectionId => 11,
+			Error::InvalidMintedCount => 12,
+			Error::UnauthorizedOperation => 13,
+			Error::UnexpectedCellCount => 14,
+			Error::MissingNftCell => 15,
+		}
+	}
+}
+
+/// Map Sys Errors to local Error values.
+impl From<SysError> for Error
+{
+	fn from(err: SysError) -> Self
+	{
+		use SysError::*;
+		match err
+		{
+			IndexOutOfBound => Self::IndexOutOfBound,
+			ItemMissing => Self::ItemMissing,
+			LengthNotEnough(_) => Self::LengthNotEnough,
+			Encoding => Self::Encoding,
+			Unknown(err_code) => panic!("Unexpected Sys Error: {}", err_code),
+		}
+	}
+}
+
+/// Holds the parsed values of the script args.
+#[derive(Debug)]
+struct CollectionArgs
+{
+	governance_lock_hash: Vec<u8>,
+	collection_id: Vec<u8>,
+	nft_code_hash: Vec<u8>,
+	nft_hash_type: u8,
+}
+
+/// Parse the script args into a CollectionArgs instance.
+/// Layout: Governance Lock Hash | Collection ID | NFT Code Hash | NFT Hash Type
+fn parse_args(args: &Args) -> Result<CollectionArgs, Error>
+{
+	let args: Bytes = args.unpack();
+
+	// Verify that the exact length of the arguments was given.
+	if args.len() != ARGS_LEN
+	{
+		return Err(Error::InvalidArgsLen);
+	}
+
+	let collection_args = CollectionArgs
+	{
+		governance_lock_hash: args[0..LOCK_HASH_LEN].to_vec(),
+		collection_id: args[LOCK_HASH_LEN..LOCK_HASH_LEN + COLLECTION_ID_LEN].to_vec(),
+		nft_code_hash: args[LOCK_HASH_LEN + COLLECTION_ID_LEN..ARGS_LEN - NFT_HASH_TYPE_LEN].to_vec(),
+		nft_hash_type: args[ARGS_LEN - NFT_HASH_TYPE_LEN],
+	};
+
+	Ok(collection_args)
+}
+
+/// Determine if owner mode is enabled.
+fn check_owner_mode(args: &CollectionArgs) -> Result<bool, Error>
+{
+	// Compares the governance Lock Script Hash from the args with the Lock Scripts
+	// of all input Cells to determine if a match exists.
+	let is_owner_mode = QueryIter::new(load_cell_lock_hash, Source::Input)
+		.find(|lock_hash| args.governance_lock_hash[..] == lock_hash[..]).is_some();
+
+	Ok(is_owner_mode)
+}
+
+/// Calculates a Collection ID from the first input and the output index of the Collection Cell.
+/// This uses the same method as an NFT Instance ID, which guarantees the Collection ID is unique.
+fn calculate_collection_id(seed_cell_outpoint: &OutPoint, output_index: usize) -> [u8; BLAKE2B256_HASH_LEN]
+{
+	let mut blake2b = Blake2bBuilder::new(BLAKE2B256_HASH_LEN).personal(b"ckb-default-hash").build();
+
+	blake2b.update(&seed_cell_outpoint.tx_hash().raw_data());
+	blake2b.update(&seed_cell_outpoint.index().raw_data());
+	blake2b.update(&(output_index as u32).to_le_bytes());
+
+	let mut hash: [u8; BLAKE2B256_HASH_LEN] = [0; BLAKE2B256_HASH_LEN];
+	blake2b.finalize(&mut hash);
+
+	hash
+}
+
+/// Locate the index of the first Cell in the output with the current type script.
+fn locate_group_output_index() -> Result<usize, Error>
+{
+	let script_hash = load_script_hash()?;
+	let index = QueryIter::new(load_cell_type_hash, Source::Output)
+		.position(|type_hash| type_hash == Some(script_hash))
+		.ok_or(Error::ItemMissing)?;
+
+	Ok(index)
+}
+
+/// Determine if an output Cell has the NFT type script from the args, with NFT args which reference this Collection Cell.
+/// The NFT type script verifies that the minted count matches the instances it generates.
+fn check_nft_output(args: &CollectionArgs) -> Result<bool, Error>
+{
+	let script_hash = load_script_hash()?;
+
+	let is_nft_output = QueryIter::new(load_cell_type, Source::Output).any(|type_script|
+	{
+		let type_script = match type_script
+		{
+			Some(type_script) => type_script,
+			None => return false,
+		};
+		if type_script.code_hash().raw_data()[..] != args.nft_code_hash[..] || type_script.hash_type().as_slice()[0] != args.nft_hash_type
+		{
+			return false;
+		}
+
+		// NFT args layout: Governance Hash | Flags | Extended Flags (optional) | Collection Type Hash (optional) | ...
+		let nft_args: Bytes = type_script.args().unpack();
+		let flags = match nft_args.get(NFT_ARGS_FLAGS_OFFSET)
+		{
+			Some(flags) => *flags,
+			None => return false,
+		};
+		if flags & NFT_ARGS_FLAG_COLLECTION == 0
+		{
+			return false;
+		}
+		let mut offset = NFT_ARGS_FLAGS_OFFSET + NFT_ARGS_FLAGS_LEN;
+		if flags & NFT_ARGS_FLAG_EXTENDED != 0
+		{
+			offset += NFT_ARGS_EXTENDED_FLAGS_LEN;
+		}
+
+		nft_args.get(offset..offset + BLAKE2B256_HASH_LEN).map_or(false, |collection_type_hash| collection_type_hash == &script_hash[..])
+	});
+
+	Ok(is_nft_output)
+}
+
+/// Load and validate the data of a Collection Cell, returning the minted count.
+fn load_minted_count(index: usize, source: Source) -> Result<u128, Error>
+{
+	let cell_data = load_cell_data(index, source)?;
+	let collection_info = CollectionInfoReader::from_slice(&cell_data).or(Err(Error::Encoding))?;
+	let minted: [u8; U128_LEN] = collection_info.minted().raw_data().try_into().or(Err(Error::Encoding))?;
+
+	Ok(u128::from_le_bytes(minted))
+}
+
+fn main() -> Result<(), Error>
+{
+	// Load and parse arguments from the current script.
+	let script = load_script()?;
+	let args = parse_args(&script.args())?;
+
+	// Detect owner mode.
+	let owner_mode = check_owner_mode(&args)?;
+	// debug!("Owner Mode: {}", owner_mode);
+
+	// All changes to a Collection Cell, including creation and destruction, are made by the owner.
+	if !owner_mode
+	{
+		return Err(Error::UnauthorizedOperation);
+	}
+
+	// Count the Collection Cells in the group input and group output.
+	let group_input_count = QueryIter::new(load_cell_data, Source::GroupInput).count();
+	let group_output_count = QueryIter::new(load_cell_data, Source::GroupOutput).count();
+
+	// A Collection ID can only exist in a single Cell.
+	if group_input_count > 1 || group_output_count > 1
+	{
+		return Err(Error::UnexpectedCellCount);
+	}
+
+	// Destruction of a Collection Cell has no further requirements.
+	if group_output_count == 0
+	{
+		return Ok(());
+	}
+
+	// Validate the structure of the output data.
+	let output_minted = load_minted_count(0, Source::GroupOutput)?;
+
+	if group_input_count == 0
+	{
+		// debug!("Operation: Create");
+
+		// The Collection ID must be derived from the Seed Cell to ensure it is unique.
+		let seed_cell_outpoint = load_input(0, Source::Input)?.previous_output();
+		let collection_id = calculate_collection_id(&seed_cell_outpoint, locate_group_output_index()?);
+		if args.collection_id[..] != collection_id[..]
+		{
+			return Err(Error::InvalidCollectionId);
+		}
+
+		// A new collection starts with no instances minted.
+		if output_minted != 0
+		{
+			return Err(Error::InvalidMintedCount);
+		}
+	}
+	else
+	{
+		// debug!("Operation: Update");
+
+		// The minted count can never decrease.
+		let input_minted = load_minted_count(0, Source::GroupInput)?;
+		if output_minted < input_minted
+		{
+			return Err(Error::InvalidMintedCount);
+		}
+
+		// The minted count can only increase when instances of the collection are generated, which prevents gaps in Serial numbers.
+		if output_minted > input_minted && !check_nft_output(&args)?
+		{
+			return Err(Error::MissingNftCell);
+		}
+	}
+
+	Ok(())
+}
@@ -12,13 +12,14 @@
 #![allow(dead_code)]
 
 // Import from `core` instead of from `std` since we are in no-std mode.
+use core::cmp;
 use core::convert::TryInto;
 use core::option::Option;
 use core::result::Result;
 
 // Import heap related library from `alloc` since we are in no-std mode.
 // https://doc.rust-lang.org/alloc/index.html
-use alloc::{collections::btree_set::BTreeSet, vec, vec::Vec};
+use alloc::{collections::btree_map::BTreeMap, collections::btree_set::BTreeSet, vec, vec::Vec};
 
 // Import Blake2b functionality.
 use blake2b_ref::Blake2bBuilder;
@@ -30,22 +31,95 @@ use ckb_std::ckb_constants::Source;
 use ckb_std::ckb_types::{bytes::Bytes, packed::Bytes as Args, packed::CellOutput as Cell, packed::Script, packed::OutPoint, prelude::*};
 use ckb_std::dynamic_loading::{CKBDLContext, Symbol};
 use ckb_std::error::{SysError};
-use ckb_std::high_level::{load_cell, load_cell_data, load_cell_lock_hash, load_cell_type_hash, load_input, load_script, load_script_hash, load_tx_hash, QueryIter};
+use ckb_std::high_level::{load_cell, load_cell_capacity, load_cell_data, load_cell_lock_hash, load_cell_type_hash, load_input, load_script, load_script_hash, load_tx_hash, load_witness_args, QueryIter};
+use ckb_std::syscalls;
+
+// Import the generated Molecule bindings for NFT data.
+use molecule::{unpack_number, NUMBER_SIZE};
+mod schemas;
+use schemas::allowlist::{AllowlistClaimVecReader, AllowlistInfoReader};
+use schemas::collection::CollectionInfoReader;
+use schemas::nft::{Byte32OptReader, Byte32Reader, CustomReader, NftDataReader, NftWitnessReader, Uint128OptReader};
 
 // Constants
 const BLAKE2B256_HASH_LEN: usize = 32; // Number of bytes for a Blake2b-256 hash.
 const CKBDL_CONTEXT_SIZE: usize = 64 * 1024;
 const CODE_HASH_NULL: [u8; 32] = [0u8; 32];
+const CUSTOM_CHUNK_LEN: usize = 4 * 1024; // Number of bytes of the Custom field loaded at a time when calculating its digest.
+const U32_LEN: usize = 4; // Number of bytes for a 32-bit unsigned integer.
 const U128_LEN: usize = 16; // Number of bytes for a 128-bit unsigned integer.
 const INSTANCE_ID_LEN: usize = BLAKE2B256_HASH_LEN; // Number of bytes in the Instance ID field.
 const LOCK_HASH_LEN: usize = BLAKE2B256_HASH_LEN; // Number of bytes for a lock hash. (Blake2b 32 bytes)
+const NFT_DATA_FLAGS_LEN: usize = 1; // Number of bytes in the Flags field of versioned NFT data.
+const NFT_DATA_MAGIC: [u8; NFT_DATA_MAGIC_LEN] = *b"NFTDATA\xff"; // Marker which identifies versioned NFT data.
+const NFT_DATA_MAGIC_LEN: usize = 8; // Number of bytes in the marker of versioned NFT data.
+const NFT_DATA_V0: u8 = 0; // Version of the original unversioned layout: Instance ID | Quantity | Token Logic | Custom
+const NFT_DATA_V1: u8 = 1; // Version of the layout: Magic | Version | Flags | Instance ID | Quantity | Token Logic | Custom
+const NFT_DATA_V1_FLAGS_MASK: u8 = 0b0000_0000; // Flags defined for v1. All other bits are reserved and must be zero.
+const NFT_DATA_V2: u8 = 2; // Version of the layout: Magic | Version | Flags | Serial (optional) | NftData (Molecule, see schemas/nft.mol)
+const NFT_DATA_V2_FLAG_SERIAL: u8 = 0b0000_0001; // The Serial field is present in v2 NFT data.
+const NFT_DATA_V2_FLAGS_MASK: u8 = NFT_DATA_V2_FLAG_SERIAL; // Flags defined for v2. All other bits are reserved and must be zero.
+const NFT_DATA_V2_TABLE_HEADER_LEN: usize = NUMBER_SIZE * (NftDataReader::FIELD_COUNT + 1); // Number of bytes in the header of the NftData Molecule table.
+const NFT_DATA_VERSION_LEN: usize = 1; // Number of bytes in the Version field of versioned NFT data.
+const NFT_DATA_PREFIX_LEN: usize = NFT_DATA_MAGIC_LEN + NFT_DATA_VERSION_LEN + NFT_DATA_FLAGS_LEN; // Number of bytes preceding the Instance ID in versioned NFT data.
 const QUANTITY_LEN: usize = U128_LEN; // Number of bytes in the quantity field.
+const SERIAL_LEN: usize = U128_LEN; // Number of bytes in the Serial field.
 const TOKEN_LOGIC_FUNCTION: &[u8] = b"token_logic";
+const TOKEN_LOGIC_MINT_FUNCTION: &[u8] = b"token_logic_mint"; // Function executed for generated instances when ARGS_EXTENDED_FLAG_MINT_TOKEN_LOGIC is enabled.
 const TOKEN_LOGIC_LEN: usize = BLAKE2B256_HASH_LEN; // Number of bytes in a Token Logic field.
 const ARGS_LEN: usize = LOCK_HASH_LEN; // Number of bytes required for args. (32 bytes)
+const ARGS_FLAGS_LEN: usize = 1; // Number of bytes in the optional Flags field of the args.
+const COLLECTION_TYPE_HASH_LEN: usize = BLAKE2B256_HASH_LEN; // Number of bytes for the type hash of a Collection Cell.
+const GOVERNANCE_MODE_LOCK: u8 = 0; // Owner mode requires an input with the governance lock hash. This is the default.
+const GOVERNANCE_MODE_MULTI_LOCK: u8 = 1; // Owner mode requires inputs with at least M of N governance lock hashes. Params: Threshold (M) | Additional Lock Count | Additional Lock Hashes
+const GOVERNANCE_MODE_TYPE: u8 = 2; // Owner mode requires an input with a type script hash matching the governance hash, such as a type-id admin Cell.
+const GOVERNANCE_MODE_SIGNATURE: u8 = 3; // Owner mode requires a witness signature over the tx hash by the public key matching the governance hash. (Blake2b of the compressed secp256k1 public key) Params: Signature Library Code Hash (a ckb-lib-secp256k1 deployment)
+const GOVERNANCE_MODE_ROLES: u8 = 4; // Owner mode requires an input with the governance lock hash, and generation requires an input with the minter lock hash instead. Params: Minter Lock Hash
+const GOVERNANCE_MODE_ADMIN_NFT: u8 = 5; // Owner mode requires an input with the admin NFT of the NFT type hash matching the governance hash, which must be carried through to the output unchanged. Params: Admin Instance ID
+const GOVERNANCE_MODE_VOUCHER: u8 = 6; // Owner mode requires an input with the governance lock hash, and generation is also allowed with a mint voucher signed by the issuer. Params: Issuer Public Key Hash | Signature Library Code Hash (a ckb-lib-secp256k1 deployment)
+const GOVERNANCE_MODE_ALLOWLIST: u8 = 7; // Owner mode requires an input with the governance lock hash, and generation is also allowed for leaves claimed from an Allowlist Cell. Params: Allowlist Type Hash
+const GOVERNANCE_MODE_PAID_MINT: u8 = 8; // Owner mode requires an input with the governance lock hash, and generation is also allowed by anyone who pays the price to the governance lock in Cells whose data is the NFT type hash. Params: Price (u64 LE, shannons per instance)
+const PRICE_LEN: usize = 8; // Number of bytes for the price of an instance in shannons.
+const ALLOWLIST_TYPE_HASH_LEN: usize = BLAKE2B256_HASH_LEN; // Number of bytes for the type hash of an Allowlist Cell.
+const PUBLIC_KEY_LEN: usize = 33; // Number of bytes for a compressed secp256k1 public key recovered by a signature library.
+const PUBLIC_KEY_HASH_LEN: usize = BLAKE2B256_HASH_LEN; // Number of bytes for a public key hash. (Blake2b of the compressed public key)
+const SIGNATURE_CKBDL_CONTEXT_SIZE: usize = 128 * 1024;
+const SIGNATURE_LIBRARY_CODE_HASH_LEN: usize = BLAKE2B256_HASH_LEN; // Number of bytes for the code hash of a signature library.
+const SIGNATURE_PREFILLED_DATA_LEN: usize = 1024 * 1024; // Number of bytes of prefilled data required by the ckb-lib-secp256k1 signature library.
+const SIGNATURE_LOAD_PREFILLED_DATA_FUNCTION: &[u8] = b"load_prefilled_data";
+const SIGNATURE_FUNCTION: &[u8] = b"validate_signature";
+const ARGS_FLAG_SOULBOUND: u8 = 0b0000_0001; // NFTs cannot be moved to a different lock outside of owner mode.
+const ARGS_FLAG_NON_FUNGIBLE: u8 = 0b0000_0010; // Every Instance ID must have a quantity of exactly one and cannot be split.
+const ARGS_FLAG_IMMUTABLE_CUSTOM: u8 = 0b0000_0100; // The Custom field cannot be changed outside of owner mode, regardless of Token Logic.
+const ARGS_FLAG_NO_HOLDER_BURN: u8 = 0b0000_1000; // NFTs cannot be burned outside of owner mode.
+const ARGS_FLAG_COLLECTION: u8 = 0b0001_0000; // Generation requires updating the Collection Cell whose type hash follows the Flags field.
+const ARGS_FLAG_SERIAL: u8 = 0b0010_0000; // Every instance has a sequential Serial number counted by the Collection Cell. Requires ARGS_FLAG_COLLECTION.
+const ARGS_FLAG_GOVERNANCE: u8 = 0b0100_0000; // A Governance Mode and its parameters follow the Collection Type Hash.
+const ARGS_FLAG_EXTENDED: u8 = 0b1000_0000; // An Extended Flags field follows the Flags field.
+const ARGS_FLAGS_MASK: u8 = ARGS_FLAG_SOULBOUND | ARGS_FLAG_NON_FUNGIBLE | ARGS_FLAG_IMMUTABLE_CUSTOM | ARGS_FLAG_NO_HOLDER_BURN | ARGS_FLAG_COLLECTION | ARGS_FLAG_SERIAL | ARGS_FLAG_GOVERNANCE | ARGS_FLAG_EXTENDED; // Flags defined for args. All other bits are reserved and must be zero.
+const ARGS_EXTENDED_FLAGS_LEN: usize = 1; // Number of bytes in the Extended Flags field of the args.
+const ARGS_EXTENDED_FLAG_REISSUE: u8 = 0b0000_0001; // The quantity of an existing instance can be increased in owner mode.
+const ARGS_EXTENDED_FLAG_INPUT_TOKEN_LOGIC: u8 = 0b0000_0010; // The Token Logic of the group input is executed on every transfer and burn outside of owner mode.
+const ARGS_EXTENDED_FLAG_MINT_TOKEN_LOGIC: u8 = 0b0000_0100; // The mint function of the Token Logic is executed for every generated instance, instead of only being validated.
+const ARGS_EXTENDED_FLAGS_MASK: u8 = ARGS_EXTENDED_FLAG_REISSUE | ARGS_EXTENDED_FLAG_INPUT_TOKEN_LOGIC | ARGS_EXTENDED_FLAG_MINT_TOKEN_LOGIC; // Extended flags defined for args. All other bits are reserved and must be zero.
+const OPERATION_GENERATE: u8 = 0; // A group output with an Instance ID which is not in the group input.
+const OPERATION_TRANSFER: u8 = 1; // A group output with the Token Logic and Custom of the group input, or a group input whose quantity is carried through to the group output.
+const OPERATION_UPDATE: u8 = 2; // A group output with an Instance ID in the group input, but a different Token Logic or Custom.
+const OPERATION_BURN: u8 = 3; // A group input whose Instance ID is missing from the group output or has a reduced quantity.
+const OPERATION_REISSUE: u8 = 4; // A group output with an Instance ID in the group input whose total quantity is increased. Reissuing requires this intent.
+const EVENT_MINT: u8 = 0; // The increase of the total quantity of an instance.
+const EVENT_TRANSFER: u8 = 1; // The quantity of an instance received by a lock which did not hold it, excluding minted quantity.
+const EVENT_BURN: u8 = 2; // The decrease of the total quantity of an instance.
+const EVENT_UPDATE: u8 = 3; // The quantity of an instance received by a Token Logic and Custom pairing which did not hold it, excluding minted quantity.
+const NFT_HEADER_V0_LEN: usize = INSTANCE_ID_LEN + QUANTITY_LEN + TOKEN_LOGIC_LEN; // Number of bytes preceding the Custom field in v0 NFT data.
+const NFT_HEADER_V1_LEN: usize = NFT_DATA_PREFIX_LEN + NFT_HEADER_V0_LEN; // Number of bytes preceding the Custom field in v1 NFT data.
+const NFT_HEADER_V2_MAX_LEN: usize = NFT_DATA_PREFIX_LEN + SERIAL_LEN + NFT_DATA_V2_TABLE_HEADER_LEN + NFT_HEADER_V0_LEN; // Maximum number of bytes preceding the Custom field in v2 NFT data.
+const NFT_HEADER_MAX_LEN: usize = NFT_HEADER_V2_MAX_LEN; // Number of bytes in the largest header of all supported versions.
 
 entry!(program_entry);
-default_alloc!();
+// The heap is raised from the default of 516K so it can hold the 1M of prefilled data allocated when the signature library is loaded.
+// The heap is a zero-initialized static region outside of the binary, so other governance modes only reserve VM memory they never touch.
+default_alloc!(4 * 1024, 1400 * 1024, 64);
 
 /// Program entry point.
 fn program_entry() -> i8
@@ -68,18 +142,43 @@ enum Error
 	ItemMissing,
 	LengthNotEnough,
 	Encoding,
+	DuplicateSalt,
+	EventMismatch,
+	ImmutableCustom,
+	InsufficientPayment,
+	InvalidAdminNft,
+	InvalidAllowlistClaim,
+	InvalidArgsFlags,
+	InvalidArgsGovernance,
 	InvalidArgsLen,
+	InvalidCollectionMintedCount,
+	InvalidEvent,
+	InvalidFlags,
 	InvalidInstanceId,
 	InvalidInstanceIdLength,
+	InvalidOperationIntent,
 	InvalidQuantity,
 	InvalidQuantityLength,
+	InvalidSeedIndex,
+	InvalidSerial,
+	InvalidSignature,
 	InvalidStructure,
 	InvalidTokenLogicLength,
+	MissingCollectionCell,
+	MissingSignatureFunction,
+	MissingSignatureLibraryCellDep,
 	MissingTokenLogicCellDep,
 	MissingTokenLogicFunction,
+	MissingTokenLogicMintFunction,
+	NonFungibleQuantity,
+	NonTransferable,
+	OperationIntentMismatch,
+	QuantityOverflow,
+	UnauthorizedBurn,
 	UnauthorizedOperation,
 	UnexpectedCellMismatch,
 	UnexpectedTokenLogicErrorCode,
+	UnsupportedVersion,
 	TokenLogicError(i8),
 }
 
@@ -105,6 +204,31 @@ impl From<Error> for i8
 			Error::UnauthorizedOperation => 19,
 			Error::UnexpectedCellMismatch => 20,
 			Error::UnexpectedTokenLogicErrorCode => 21,
+			Error::QuantityOverflow => 22,
+			Error::UnsupportedVersion => 23,
+			Error::InvalidFlags => 24,
+			Error::InvalidArgsFlags => 25,
+			Error::NonTransferable => 26,
+			Error::NonFungibleQuantity => 27,
+			Error::ImmutableCustom => 28,
+			Error::UnauthorizedBurn => 29,
+			Error::InvalidCollectionMintedCount => 30,
+			Error::MissingCollectionCell => 31,
+			Error::InvalidSerial => 32,
+			Error::InvalidArgsGovernance => 33,
+			Error::InvalidSignature => 34,
+			Error::MissingSignatureLibraryCellDep => 35,
+			Error::MissingSignatureFunction => 36,
+			Error::InvalidAdminNft => 37,
+			Error::InvalidAllowlistClaim => 38,
+			Error::InsufficientPayment => 39,
+			Error::InvalidSeedIndex => 40,
+			Error::DuplicateSalt => 41,
+			Error::InvalidOperationIntent => 42,
+			Error::OperationIntentMismatch => 43,
+			Error::InvalidEvent => 44,
+			Error::EventMismatch => 45,
+			Error::MissingTokenLogicMintFunction => 46,
 			Error::TokenLogicError(e) => e,
 		}
 	}
@@ -127,26 +251,725 @@ impl From<SysError> for Error
 	}
 }
 
-/// Determine if owner mode is enabled.
-fn check_owner_mode(args: &Args) -> Result<bool, Error>
+/// The method used to determine if owner mode is enabled.
+#[derive(Debug)]
+enum Governance
+{
+	Lock(Vec<u8>),
+	MultiLock { lock_hashes: Vec<Vec<u8>>, threshold: usize },
+	Type(Vec<u8>),
+	Signature { public_key_hash: Vec<u8>, library_code_hash: Vec<u8> },
+	Roles { updater_lock_hash: Vec<u8>, minter_lock_hash: Vec<u8> },
+	AdminNft { type_hash: Vec<u8>, instance_id: Vec<u8> },
+	Voucher { lock_hash: Vec<u8>, issuer_public_key_hash: Vec<u8>, library_code_hash: Vec<u8> },
+	Allowlist { lock_hash: Vec<u8>, allowlist_type_hash: Vec<u8> },
+	PaidMint { lock_hash: Vec<u8>, price: u64 },
+}
+
+/// Holds the parsed values of the script args.
+#[derive(Debug)]
+struct NftArgs
+{
+	governance: Governance,
+	flags: u8,
+	extended_flags: u8,
+	collection_type_hash: Option<Vec<u8>>,
+}
+
+/// Parse the script args into an NftArgs instance.
+/// Layout: Governance Hash | Flags (optional) | Extended Flags (required by ARGS_FLAG_EXTENDED) | Collection Type Hash (required by ARGS_FLAG_COLLECTION) | Governance Mode and Params (required by ARGS_FLAG_GOVERNANCE)
+fn parse_args(args: &Args) -> Result<NftArgs, Error>
 {
-	// Compares the Lock Script Hash from the first 32 bytes of the args with the Lock Scripts
-	// of all input Cells to determine if a match exists.
 	let args: Bytes = args.unpack();
-	let is_owner_mode = QueryIter::new(load_cell_lock_hash, Source::Input)
-		.find(|lock_hash| args[0..LOCK_HASH_LEN] == lock_hash[..]).is_some();
 
-	Ok(is_owner_mode)
+	// Verify that the minimum length of the arguments was given.
+	if args.len() < ARGS_LEN
+	{
+		return Err(Error::InvalidArgsLen);
+	}
+
+	// Extract the Flags field if it exists. All flags are disabled when it is omitted.
+	let mut flags = 0;
+	if args.len() > ARGS_LEN
+	{
+		flags = args[ARGS_LEN];
+	}
+
+	// Ensure that no reserved flags are set.
+	if flags & !ARGS_FLAGS_MASK != 0
+	{
+		return Err(Error::InvalidArgsFlags);
+	}
+
+	// Serial numbers are counted by the Collection Cell, so the collection flag must also be set.
+	if flags & ARGS_FLAG_SERIAL != 0 && flags & ARGS_FLAG_COLLECTION == 0
+	{
+		return Err(Error::InvalidArgsFlags);
+	}
+
+	// Optional fields follow the Flags field in a fixed order.
+	let mut offset = ARGS_LEN + ARGS_FLAGS_LEN;
+
+	// Extract the Extended Flags field if the extended flag is set. It is a part of the flags, so it cannot be omitted when the extended flag is set.
+	let mut extended_flags = 0;
+	if flags & ARGS_FLAG_EXTENDED != 0
+	{
+		if args.len() < offset + ARGS_EXTENDED_FLAGS_LEN
+		{
+			return Err(Error::InvalidArgsFlags);
+		}
+
+		extended_flags = args[offset];
+		offset += ARGS_EXTENDED_FLAGS_LEN;
+	}
+
+	// Ensure that no reserved extended flags are set.
+	if extended_flags & !ARGS_EXTENDED_FLAGS_MASK != 0
+	{
+		return Err(Error::InvalidArgsFlags);
+	}
+
+	// Extract the Collection Type Hash if the collection flag is set.
+	let mut collection_type_hash = None;
+	if flags & ARGS_FLAG_COLLECTION != 0
+	{
+		if args.len() < offset + COLLECTION_TYPE_HASH_LEN
+		{
+			return Err(Error::InvalidArgsLen);
+		}
+
+		collection_type_hash = Some(args[offset..offset + COLLECTION_TYPE_HASH_LEN].to_vec());
+		offset += COLLECTION_TYPE_HASH_LEN;
+	}
+
+	// Extract the Governance Mode if the governance flag is set. The single governance lock is used when it is omitted.
+	let mut governance = Governance::Lock(args[0..LOCK_HASH_LEN].to_vec());
+	if flags & ARGS_FLAG_GOVERNANCE != 0
+	{
+		governance = parse_args_governance(&args, offset)?;
+	}
+
+	// The Collection Cell can only be updated by its owner, so the modes which allow generation without the owner cannot be used with a collection.
+	if collection_type_hash.is_some()
+	{
+		match governance
+		{
+			Governance::Voucher { .. } | Governance::Allowlist { .. } | Governance::PaidMint { .. } => return Err(Error::InvalidArgsFlags),
+			_ => (),
+		}
+	}
+
+	let nft_args = NftArgs
+	{
+		governance: governance,
+		flags: flags,
+		extended_flags: extended_flags,
+		collection_type_hash: collection_type_hash,
+	};
+
+	Ok(nft_args)
+}
+
+/// Parse the Governance Mode and its parameters beginning at the specified offset of the args.
+/// The Governance Hash at the start of the args is a lock hash in the lock, roles, voucher, allowlist, and paid mint modes, a type hash in the type and admin NFT modes, and a public key hash in the signature mode.
+fn parse_args_governance(args: &Bytes, offset: usize) -> Result<Governance, Error>
+{
+	if args.len() < offset + 1
+	{
+		return Err(Error::InvalidArgsLen);
+	}
+	let mode = args[offset];
+	let offset = offset + 1;
+
+	match mode
+	{
+		GOVERNANCE_MODE_LOCK => Ok(Governance::Lock(args[0..LOCK_HASH_LEN].to_vec())),
+		GOVERNANCE_MODE_MULTI_LOCK =>
+		{
+			// Extract the Threshold and Count fields.
+			if args.len() < offset + 2
+			{
+				return Err(Error::InvalidArgsLen);
+			}
+			let threshold = args[offset] as usize;
+			let count = args[offset + 1] as usize;
+			let offset = offset + 2;
+
+			// Extract the additional lock hashes.
+			if args.len() < offset + count * LOCK_HASH_LEN
+			{
+				return Err(Error::InvalidArgsLen);
+			}
+			let mut lock_hashes = vec!(args[0..LOCK_HASH_LEN].to_vec());
+			for lock_hash in args[offset..offset + count * LOCK_HASH_LEN].chunks(LOCK_HASH_LEN)
+			{
+				// A duplicate lock hash would allow a single lock to be counted more than once.
+				if lock_hashes.iter().any(|x| x[..] == lock_hash[..])
+				{
+					return Err(Error::InvalidArgsGovernance);
+				}
+				lock_hashes.push(lock_hash.to_vec());
+			}
+
+			// The threshold must be achievable and cannot be zero.
+			if threshold == 0 || threshold > lock_hashes.len()
+			{
+				return Err(Error::InvalidArgsGovernance);
+			}
+
+			Ok(Governance::MultiLock { lock_hashes: lock_hashes, threshold: threshold })
+		},
+		GOVERNANCE_MODE_TYPE => Ok(Governance::Type(args[0..LOCK_HASH_LEN].to_vec())),
+		GOVERNANCE_MODE_SIGNATURE =>
+		{
+			// Extract the code hash of the signature library.
+			if args.len() < offset + SIGNATURE_LIBRARY_CODE_HASH_LEN
+			{
+				return Err(Error::InvalidArgsLen);
+			}
+			let library_code_hash = args[offset..offset + SIGNATURE_LIBRARY_CODE_HASH_LEN].to_vec();
+
+			Ok(Governance::Signature { public_key_hash: args[0..PUBLIC_KEY_HASH_LEN].to_vec(), library_code_hash: library_code_hash })
+		},
+		GOVERNANCE_MODE_ROLES =>
+		{
+			// Extract the minter lock hash.
+			if args.len() < offset + LOCK_HASH_LEN
+			{
+				return Err(Error::InvalidArgsLen);
+			}
+			let minter_lock_hash = args[offset..offset + LOCK_HASH_LEN].to_vec();
+
+			Ok(Governance::Roles { updater_lock_hash: args[0..LOCK_HASH_LEN].to_vec(), minter_lock_hash: minter_lock_hash })
+		},
+		GOVERNANCE_MODE_ADMIN_NFT =>
+		{
+			// Extract the Instance ID of the admin NFT.
+			if args.len() < offset + INSTANCE_ID_LEN
+			{
+				return Err(Error::InvalidArgsLen);
+			}
+			let instance_id = args[offset..offset + INSTANCE_ID_LEN].to_vec();
+
+			Ok(Governance::AdminNft { type_hash: args[0..LOCK_HASH_LEN].to_vec(), instance_id: instance_id })
+		},
+		GOVERNANCE_MODE_VOUCHER =>
+		{
+			// Extract the issuer public key hash and the code hash of the signature library.
+			if args.len() < offset + PUBLIC_KEY_HASH_LEN + SIGNATURE_LIBRARY_CODE_HASH_LEN
+			{
+				return Err(Error::InvalidArgsLen);
+			}
+			let issuer_public_key_hash = args[offset..offset + PUBLIC_KEY_HASH_LEN].to_vec();
+			let offset = offset + PUBLIC_KEY_HASH_LEN;
+			let library_code_hash = args[offset..offset + SIGNATURE_LIBRARY_CODE_HASH_LEN].to_vec();
+
+			Ok(Governance::Voucher { lock_hash: args[0..LOCK_HASH_LEN].to_vec(), issuer_public_key_hash: issuer_public_key_hash, library_code_hash: library_code_hash })
+		},
+		GOVERNANCE_MODE_ALLOWLIST =>
+		{
+			// Extract the type hash of the Allowlist Cell.
+			if args.len() < offset + ALLOWLIST_TYPE_HASH_LEN
+			{
+				return Err(Error::InvalidArgsLen);
+			}
+			let allowlist_type_hash = args[offset..offset + ALLOWLIST_TYPE_HASH_LEN].to_vec();
+
+			Ok(Governance::Allowlist { lock_hash: args[0..LOCK_HASH_LEN].to_vec(), allowlist_type_hash: allowlist_type_hash })
+		},
+		GOVERNANCE_MODE_PAID_MINT =>
+		{
+			// Extract the price of an instance.
+			if args.len() < offset + PRICE_LEN
+			{
+				return Err(Error::InvalidArgsLen);
+			}
+			let price: [u8; PRICE_LEN] = args[offset..offset + PRICE_LEN].try_into().or(Err(Error::InvalidArgsLen))?;
+
+			Ok(Governance::PaidMint { lock_hash: args[0..LOCK_HASH_LEN].to_vec(), price: u64::from_le_bytes(price) })
+		},
+		_ => Err(Error::InvalidArgsGovernance),
+	}
+}
+
+/// Determine if owner mode is enabled.
+fn check_owner_mode(args: &NftArgs) -> Result<bool, Error>
+{
+	match &args.governance
+	{
+		Governance::Lock(governance_lock_hash) => Ok(check_input_lock_hash(governance_lock_hash)),
+		Governance::MultiLock { lock_hashes, threshold } =>
+		{
+			// Count the governance locks which are present on at least one input Cell.
+			let input_lock_hashes: BTreeSet<[u8; LOCK_HASH_LEN]> = QueryIter::new(load_cell_lock_hash, Source::Input).collect();
+			let matches = lock_hashes.iter().filter(|lock_hash| input_lock_hashes.iter().any(|x| x[..] == lock_hash[..])).count();
+
+			Ok(matches >= *threshold)
+		},
+		Governance::Type(governance_type_hash) =>
+		{
+			// Compares the governance Type Script Hash from the args with the Type Scripts
+			// of all input Cells to determine if a match exists. The Lock Script of the admin Cell can be rotated freely.
+			let is_owner_mode = QueryIter::new(load_cell_type_hash, Source::Input)
+				.find(|type_hash| type_hash.map_or(false, |type_hash| governance_type_hash[..] == type_hash[..])).is_some();
+
+			Ok(is_owner_mode)
+		},
+		Governance::Signature { public_key_hash, library_code_hash } =>
+		{
+			// Owner mode is not enabled when no signature is provided.
+			let signature = match load_owner_signature()?
+			{
+				Some(signature) => signature,
+				None => return Ok(false),
+			};
+
+			// A provided signature must be valid and made by the governance public key.
+			let message = load_tx_hash()?;
+			let signer_public_key_hash = verify_signature(library_code_hash, &message, &signature)?;
+			if signer_public_key_hash[..] != public_key_hash[..]
+			{
+				return Err(Error::InvalidSignature);
+			}
+
+			Ok(true)
+		},
+		Governance::Roles { updater_lock_hash, .. } => Ok(check_input_lock_hash(updater_lock_hash)),
+		Governance::Voucher { lock_hash, .. } => Ok(check_input_lock_hash(lock_hash)),
+		Governance::Allowlist { lock_hash, .. } => Ok(check_input_lock_hash(lock_hash)),
+		Governance::PaidMint { lock_hash, .. } => Ok(check_input_lock_hash(lock_hash)),
+		Governance::AdminNft { type_hash, instance_id } =>
+		{
+			// Owner mode is not enabled when the admin NFT is not in the input.
+			// Any quantity of the admin instance grants owner mode, so every holder of a fraction of a fungible admin instance is an admin.
+			// An issuer who requires a single admin must use an admin instance which cannot be split, such as one with ARGS_FLAG_NON_FUNGIBLE.
+			let input_admin_nft_cells = collect_admin_nft_cells(type_hash, instance_id, Source::Input)?;
+			if input_admin_nft_cells.is_empty()
+			{
+				return Ok(false);
+			}
+
+			// The admin NFT must be carried through to the output with the same Lock Script and data.
+			let output_admin_nft_cells = collect_admin_nft_cells(type_hash, instance_id, Source::Output)?;
+			if input_admin_nft_cells != output_admin_nft_cells
+			{
+				return Err(Error::InvalidAdminNft);
+			}
+
+			Ok(true)
+		},
+	}
+}
+
+/// Determine if minter mode is enabled, which allows generation operations.
+/// Minting is a part of owner mode unless the roles governance mode assigns it to a separate lock.
+fn check_minter_mode(args: &NftArgs, owner_mode: bool) -> Result<bool, Error>
+{
+	match &args.governance
+	{
+		Governance::Roles { minter_lock_hash, .. } => Ok(check_input_lock_hash(minter_lock_hash)),
+		_ => Ok(owner_mode),
+	}
+}
+
+/// Determine if the group output at the specified index has a valid mint voucher, which allows a generation operation without minter mode.
+/// The voucher is a signature by the issuer in the NFT witness of the group output.
+fn check_mint_voucher(args: &NftArgs, script_hash: &[u8; BLAKE2B256_HASH_LEN], index: usize, instance_id: &Vec<u8>, nft_witness: &Option<NftWitness>) -> Result<bool, Error>
+{
+	let (issuer_public_key_hash, library_code_hash) = match &args.governance
+	{
+		Governance::Voucher { issuer_public_key_hash, library_code_hash, .. } => (issuer_public_key_hash, library_code_hash),
+		_ => return Ok(false),
+	};
+
+	// No voucher is available when the group output has no NFT witness.
+	let voucher = match nft_witness.as_ref().and_then(|x| x.voucher.as_ref())
+	{
+		Some(voucher) => voucher,
+		None => return Ok(false),
+	};
+
+	// A provided voucher must be valid and signed by the issuer public key.
+	// The Cell data is hashed in chunks so a large Custom field is never held in memory.
+	let cell_data_digest = load_cell_data_digest(index, Source::GroupOutput)?;
+	let message = calculate_mint_voucher_message(script_hash, instance_id, &cell_data_digest.hash, &load_cell_lock_hash(index, Source::GroupOutput)?);
+	let signer_public_key_hash = verify_signature(library_code_hash, &message, &voucher)?;
+	if signer_public_key_hash[..] != issuer_public_key_hash[..]
+	{
+		return Err(Error::InvalidSignature);
+	}
+
+	Ok(true)
+}
+
+/// Calculates the message signed by a mint voucher from the NFT type script hash, the Instance ID, the hash of the Cell data, and the recipient Lock Script hash.
+/// The Instance ID is derived from the Seed Cell, so a voucher can only be used once.
+/// The NFT type script hash binds the voucher to a single collection, since an issuer key may govern several collections.
+fn calculate_mint_voucher_message(script_hash: &[u8; BLAKE2B256_HASH_LEN], instance_id: &Vec<u8>, cell_data_hash: &[u8; BLAKE2B256_HASH_LEN], lock_hash: &[u8; LOCK_HASH_LEN]) -> [u8; BLAKE2B256_HASH_LEN]
+{
+	let mut blake2b = Blake2bBuilder::new(BLAKE2B256_HASH_LEN).personal(b"ckb-default-hash").build();
+	blake2b.update(script_hash);
+	blake2b.update(instance_id);
+	blake2b.update(cell_data_hash);
+	blake2b.update(lock_hash);
+
+	let mut hash: [u8; BLAKE2B256_HASH_LEN] = [0; BLAKE2B256_HASH_LEN];
+	blake2b.finalize(&mut hash);
+
+	hash
+}
+
+/// Collect the quantities which may be generated for each minter lock hash from the leaves newly claimed from the Allowlist Cell.
+/// The Allowlist Cell type script verifies the Merkle proof of each claim and that the minter lock is present in the input.
+fn collect_allowlist_allowances(args: &NftArgs) -> Result<BTreeMap<[u8; LOCK_HASH_LEN], u128>, Error>
+{
+	let mut allowances = BTreeMap::new();
+
+	let allowlist_type_hash = match &args.governance
+	{
+		Governance::Allowlist { allowlist_type_hash, .. } => allowlist_type_hash,
+		_ => return Ok(allowances),
+	};
+
+	// No leaves are claimed unless the Allowlist Cell is updated.
+	let is_allowlist_type_hash = |type_hash: Option<[u8; BLAKE2B256_HASH_LEN]>| type_hash.map_or(false, |type_hash| allowlist_type_hash[..] == type_hash[..]);
+	let input_index = match QueryIter::new(load_cell_type_hash, Source::Input).position(is_allowlist_type_hash)
+	{
+		Some(index) => index,
+		None => return Ok(allowances),
+	};
+	let output_index = match QueryIter::new(load_cell_type_hash, Source::Output).position(is_allowlist_type_hash)
+	{
+		Some(index) => index,
+		None => return Ok(allowances),
+	};
+	let input_claimed = load_allowlist_claimed(input_index, Source::Input)?;
+	let output_claimed = load_allowlist_claimed(output_index, Source::Output)?;
+
+	// The claims are in the input type field of the witness of the Allowlist Cell.
+	let claims: Option<Bytes> = match load_witness_args(input_index, Source::Input)
+	{
+		Ok(witness_args) => witness_args.input_type().to_opt().map(|x| x.unpack()),
+		Err(SysError::IndexOutOfBound) => None,
+		Err(err) => return Err(err.into()),
+	};
+	let claims = match claims
+	{
+		Some(claims) => claims,
+		None => return Ok(allowances),
+	};
+
+	for claim in AllowlistClaimVecReader::from_slice(&claims).or(Err(Error::Encoding))?.iter()
+	{
+		let index: [u8; U32_LEN] = claim.index().raw_data().try_into().or(Err(Error::Encoding))?;
+		let lock_hash: [u8; LOCK_HASH_LEN] = claim.lock_hash().raw_data().try_into().or(Err(Error::Encoding))?;
+		let quantity: [u8; U128_LEN] = claim.quantity().raw_data().try_into().or(Err(Error::Encoding))?;
+
+		// A claim can only be used in the transaction which claims it.
+		let index = u32::from_le_bytes(index);
+		if is_allowlist_claimed(&input_claimed, index) || !is_allowlist_claimed(&output_claimed, index)
+		{
+			return Err(Error::InvalidAllowlistClaim);
+		}
+
+		collect_nft_quantity(&mut allowances, lock_hash, u128::from_le_bytes(quantity))?;
+	}
+
+	Ok(allowances)
+}
+
+/// Load the claimed bitmap from the Allowlist Cell at the specified index of the specified source.
+fn load_allowlist_claimed(index: usize, source: Source) -> Result<Vec<u8>, Error>
+{
+	let cell_data = load_cell_data(index, source)?;
+	let allowlist_info = AllowlistInfoReader::from_slice(&cell_data).or(Err(Error::Encoding))?;
+
+	Ok(allowlist_info.claimed().raw_data().to_vec())
+}
+
+/// Determine if the bit for the specified leaf index is set in a claimed bitmap.
+fn is_allowlist_claimed(claimed: &Vec<u8>, index: u32) -> bool
+{
+	let index = index as usize;
+	index / 8 < claimed.len() && claimed[index / 8] & (1 << (index % 8)) != 0
+}
+
+/// Determine if the group output at the specified index is covered by an allowlist allowance, which allows a generation operation without minter mode.
+/// The quantity of the group output is deducted from the allowance of its lock hash.
+fn check_allowlist_allowance(allowances: &mut BTreeMap<[u8; LOCK_HASH_LEN], u128>, index: usize, quantity: u128) -> Result<bool, Error>
+{
+	let lock_hash = load_cell_lock_hash(index, Source::GroupOutput)?;
+	let allowance = match allowances.get_mut(&lock_hash)
+	{
+		Some(allowance) => allowance,
+		None => return Ok(false),
+	};
+
+	// The quantity generated for a minter cannot exceed the total quantity of its claims.
+	*allowance = allowance.checked_sub(quantity).ok_or(Error::InvalidAllowlistClaim)?;
+
+	Ok(true)
+}
+
+/// Verify that the price of the specified number of instances generated by paid minting was paid to output Cells with the governance lock and the NFT type hash as data.
+fn verify_paid_mint_payment(args: &NftArgs, script_hash: &[u8; BLAKE2B256_HASH_LEN], paid_generated_count: u128) -> Result<(), Error>
+{
+	let (lock_hash, price) = match &args.governance
+	{
+		Governance::PaidMint { lock_hash, price } => (lock_hash, price),
+		_ => return Err(Error::UnauthorizedOperation),
+	};
+
+	// The required payment is the price of each paid instance.
+	let required_payment = (*price as u128).checked_mul(paid_generated_count).ok_or(Error::QuantityOverflow)?;
+
+	// Owner mode is not enabled during paid minting, so the governance lock has no input Cells and the full capacity of its output Cells is payment.
+	// A payment Cell must have the NFT type hash as its data, so a single payment cannot be counted by multiple collections sharing a governance lock.
+	let mut payment: u128 = 0;
+	for (index, output_lock_hash) in QueryIter::new(load_cell_lock_hash, Source::Output).enumerate()
+	{
+		if lock_hash[..] != output_lock_hash[..]
+		{
+			continue;
+		}
+
+		let mut payment_script_hash = [0u8; BLAKE2B256_HASH_LEN];
+		let is_bound = match syscalls::load_cell_data(&mut payment_script_hash, 0, index, Source::Output)
+		{
+			Ok(len) => len == BLAKE2B256_HASH_LEN && payment_script_hash == *script_hash,
+			Err(SysError::LengthNotEnough(_)) => false,
+			Err(err) => return Err(err.into()),
+		};
+		if is_bound
+		{
+			payment = payment.checked_add(load_cell_capacity(index, Source::Output)? as u128).ok_or(Error::QuantityOverflow)?;
+		}
+	}
+
+	if payment < required_payment
+	{
+		return Err(Error::InsufficientPayment);
+	}
+
+	Ok(())
+}
+
+/// Compares the specified Lock Script Hash with the Lock Scripts of all input Cells to determine if a match exists.
+fn check_input_lock_hash(governance_lock_hash: &[u8]) -> bool
+{
+	QueryIter::new(load_cell_lock_hash, Source::Input)
+		.find(|lock_hash| governance_lock_hash[..] == lock_hash[..]).is_some()
+}
+
+/// Collect the lock hash and data digest of every Cell in the specified source which holds the admin NFT with the specified NFT type hash and Instance ID.
+/// The results are sorted so the input and output can be compared regardless of Cell order.
+fn collect_admin_nft_cells(type_hash: &Vec<u8>, instance_id: &Vec<u8>, source: Source) -> Result<Vec<([u8; LOCK_HASH_LEN], CustomDigest)>, Error>
+{
+	let mut admin_nft_cells = Vec::new();
+
+	for (index, cell_type_hash) in QueryIter::new(load_cell_type_hash, source).enumerate()
+	{
+		if !cell_type_hash.map_or(false, |cell_type_hash| type_hash[..] == cell_type_hash[..])
+		{
+			continue;
+		}
+
+		let nft_data = load_nft_data(index, source)?;
+		if nft_data.instance_id[..] == instance_id[..]
+		{
+			admin_nft_cells.push((load_cell_lock_hash(index, source)?, load_cell_data_digest(index, source)?));
+		}
+	}
+	admin_nft_cells.sort();
+
+	Ok(admin_nft_cells)
+}
+
+/// Load the NFT witness of the script group, if it exists.
+/// This is the NFT witness of the first group input, or the NFT witness of the first group output
+/// when the first group input has no witness, such as during generation.
+fn load_group_nft_witness() -> Result<Option<NftWitness>, Error>
+{
+	match load_witness_args(0, Source::GroupInput)
+	{
+		Ok(_) | Err(SysError::Encoding) => load_nft_witness(0, Source::GroupInput),
+		Err(SysError::IndexOutOfBound) => load_nft_witness(0, Source::GroupOutput),
+		Err(err) => Err(err.into()),
+	}
+}
+
+/// Load the owner signature from the NFT witness of the script group, if it exists.
+fn load_owner_signature() -> Result<Option<Bytes>, Error>
+{
+	Ok(load_group_nft_witness()?.and_then(|x| x.signature))
+}
+
+/// Load the NFT witness of each of the specified number of Cells in the specified source, so each witness is only parsed once.
+fn collect_nft_witnesses(count: usize, source: Source) -> Result<Vec<Option<NftWitness>>, Error>
+{
+	let mut nft_witnesses = Vec::with_capacity(count);
+
+	for index in 0..count
+	{
+		nft_witnesses.push(load_nft_witness(index, source)?);
+	}
+
+	Ok(nft_witnesses)
+}
+
+/// Holds the parsed values of an NFT witness.
+#[derive(Debug)]
+struct NftWitness
+{
+	signature: Option<Bytes>,
+	voucher: Option<Bytes>,
+	seed_index: Option<u32>,
+	salt: Option<Vec<u8>>,
+	intent: Option<u8>,
+	events: Option<Vec<NftEvent>>,
+}
+
+/// Holds the parsed values of an event record in an NFT witness.
+#[derive(Debug)]
+struct NftEvent
+{
+	kind: u8,
+	instance_id: Vec<u8>,
+	quantity: u128,
+}
+
+/// Load and parse the NFT witness at the specified index of the specified source, if it exists.
+/// The NFT witness is in the input type field of the witness of a group input, and the output type field of the witness of a group output.
+/// A witness which is empty or is not WitnessArgs has no NFT witness, since wallets leave the witnesses after the first of a lock group empty.
+fn load_nft_witness(index: usize, source: Source) -> Result<Option<NftWitness>, Error>
+{
+	let witness = match load_witness_args(index, source)
+	{
+		Ok(witness_args) if source == Source::GroupInput => witness_args.input_type().to_opt(),
+		Ok(witness_args) => witness_args.output_type().to_opt(),
+		Err(SysError::IndexOutOfBound) | Err(SysError::Encoding) => None,
+		Err(err) => return Err(err.into()),
+	};
+	let witness: Bytes = match witness
+	{
+		Some(witness) => witness.unpack(),
+		None => return Ok(None),
+	};
+
+	let nft_witness = NftWitnessReader::from_slice(&witness).or(Err(Error::Encoding))?;
+	let seed_index = match nft_witness.seed_index().to_opt()
+	{
+		Some(seed_index) => Some(u32::from_le_bytes(seed_index.raw_data().try_into().or(Err(Error::Encoding))?)),
+		None => None,
+	};
+
+	let events = match nft_witness.events().to_opt()
+	{
+		Some(events) =>
+		{
+			let mut nft_events = vec!();
+			for event in events.iter()
+			{
+				let nft_event = NftEvent
+				{
+					kind: event.kind().as_slice()[0],
+					instance_id: event.instance_id().raw_data().to_vec(),
+					quantity: u128::from_le_bytes(event.quantity().raw_data().try_into().or(Err(Error::Encoding))?),
+				};
+				nft_events.push(nft_event);
+			}
+			Some(nft_events)
+		},
+		None => None,
+	};
+
+	let nft_witness = NftWitness
+	{
+		signature: nft_witness.signature().to_opt().map(|x| x.raw_data().to_vec().into()),
+		voucher: nft_witness.voucher().to_opt().map(|x| x.raw_data().to_vec().into()),
+		seed_index: seed_index,
+		salt: nft_witness.salt().to_opt().map(|x| x.raw_data().to_vec()),
+		intent: nft_witness.intent().to_opt().map(|x| x.as_slice()[0]),
+		events: events,
+	};
+
+	Ok(Some(nft_witness))
+}
+
+/// Verify a signature over a message with the signature library in a Cell with the specified code hash.
+/// The library uses the interface of ckb-lib-secp256k1, which recovers the compressed public key of the signer from a recoverable secp256k1 signature.
+/// The public key hash of the signer is returned if the signature is valid.
+fn verify_signature(library_code_hash: &Vec<u8>, message: &[u8; BLAKE2B256_HASH_LEN], signature: &Bytes) -> Result<[u8; PUBLIC_KEY_HASH_LEN], Error>
+{
+	let library_code_hash: [u8; SIGNATURE_LIBRARY_CODE_HASH_LEN] = library_code_hash.as_slice().try_into().expect("Conversion failed");
+
+	let mut context = CKBDLContext::<[u8; SIGNATURE_CKBDL_CONTEXT_SIZE]>::new();
+	let lib = context.load(&library_code_hash).or(Err(Error::MissingSignatureLibraryCellDep))?;
+	let mut public_key = [0u8; PUBLIC_KEY_LEN];
+	let mut public_key_len = PUBLIC_KEY_LEN as u64;
+	unsafe
+	{
+		type LoadPrefilledData = unsafe extern "C" fn(data: *mut u8, len: *mut u64) -> i32;
+		type ValidateSignature = unsafe extern "C" fn(prefilled_data: *const u8, signature: *const u8, signature_len: u64, message: *const u8, message_len: u64, public_key: *mut u8, public_key_len: *mut u64) -> i32;
+		let load_prefilled_data: Symbol<LoadPrefilledData> = lib.get(SIGNATURE_LOAD_PREFILLED_DATA_FUNCTION).ok_or(Error::MissingSignatureFunction)?;
+		let validate_signature: Symbol<ValidateSignature> = lib.get(SIGNATURE_FUNCTION).ok_or(Error::MissingSignatureFunction)?;
+
+		// The prefilled data is the precomputed secp256k1 context of the library.
+		let mut prefilled_data = vec![0u8; SIGNATURE_PREFILLED_DATA_LEN];
+		let mut prefilled_data_len = SIGNATURE_PREFILLED_DATA_LEN as u64;
+		if load_prefilled_data(prefilled_data.as_mut_ptr(), &mut prefilled_data_len) != 0
+		{
+			return Err(Error::InvalidSignature);
+		}
+
+		let error_code = validate_signature(prefilled_data.as_ptr(), signature.as_ptr(), signature.len() as u64, message.as_ptr(), message.len() as u64, public_key.as_mut_ptr(), &mut public_key_len);
+		if error_code != 0 || public_key_len != PUBLIC_KEY_LEN as u64
+		{
+			return Err(Error::InvalidSignature);
+		}
+	}
+
+	let mut blake2b = Blake2bBuilder::new(BLAKE2B256_HASH_LEN).personal(b"ckb-default-hash").build();
+	blake2b.update(&public_key);
+	let mut public_key_hash = [0u8; PUBLIC_KEY_HASH_LEN];
+	blake2b.finalize(&mut public_key_hash);
+
+	Ok(public_key_hash)
+}
+
+/// Holds the length and Blake2b hash of a Custom field so it can be compared without being held in memory.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct CustomDigest
+{
+	len: usize,
+	hash: [u8; BLAKE2B256_HASH_LEN],
+}
+
+impl CustomDigest
+{
+	/// The digest of an omitted Custom field.
+	fn empty() -> Self
+	{
+		CustomDigest
+		{
+			len: 0,
+			hash: [0u8; BLAKE2B256_HASH_LEN],
+		}
+	}
 }
 
 /// Holds the parsed values of an NFT data field. 
 #[derive(Debug)]
 struct NftData
 {
+	version: u8,
+	flags: u8,
 	instance_id: Vec<u8>,
 	quantity: Option<u128>,
 	token_logic: Option<Vec<u8>>,
-	custom: Option<Vec<u8>>,
+	custom: Option<CustomDigest>,
+	custom_offset: usize,
+	serial: Option<u128>,
 }
 
 /// Holds the absolute (resolved) values of NFT data regardless on if optional fields were included. 
@@ -156,31 +979,52 @@ struct NftDataResolved
 	instance_id: Vec<u8>,
 	quantity: u128,
 	token_logic: Vec<u8>,
-	custom: Vec<u8>,
+	custom: CustomDigest,
+	serial: Option<u128>,
 }
 
-impl From<&NftData> for NftDataResolved
+impl From<NftData> for NftDataResolved
 {
-	fn from(nft_data: &NftData) -> Self
+	fn from(nft_data: NftData) -> Self
 	{
 		NftDataResolved
 		{
-			instance_id: nft_data.instance_id.clone(),
-			quantity: nft_data.quantity.clone().unwrap_or(1),
-			token_logic: nft_data.token_logic.clone().unwrap_or(CODE_HASH_NULL.to_vec()),
-			custom: nft_data.custom.clone().unwrap_or(vec!()),
+			instance_id: nft_data.instance_id,
+			quantity: nft_data.quantity.unwrap_or(1),
+			token_logic: nft_data.token_logic.unwrap_or(CODE_HASH_NULL.to_vec()),
+			custom: nft_data.custom.unwrap_or(CustomDigest::empty()),
+			serial: nft_data.serial,
 		}
 	}
 }
 
-/// Calculates and Instance ID from an output and output index.
-fn calculate_instance_id(seed_cell_outpoint: &OutPoint, output_index: usize) -> [u8; BLAKE2B256_HASH_LEN]
+/// Load the outpoint of the Seed Cell, which is the input named by the seed index in the NFT witness of the first group output.
+/// The first input is the Seed Cell when the seed index is omitted.
+fn load_seed_cell_outpoint(nft_witness: &Option<NftWitness>) -> Result<OutPoint, Error>
+{
+	let seed_index = nft_witness.as_ref().and_then(|x| x.seed_index).unwrap_or(0);
+
+	match load_input(seed_index as usize, Source::Input)
+	{
+		Ok(input) => Ok(input.previous_output()),
+		Err(SysError::IndexOutOfBound) => Err(Error::InvalidSeedIndex),
+		Err(err) => Err(err.into()),
+	}
+}
+
+/// Calculates and Instance ID from an output, output index, and optional salt.
+/// A salt replaces the output index, so a salted Instance ID can be computed before the transaction is assembled, but is still unique to its seed.
+fn calculate_instance_id(seed_cell_outpoint: &OutPoint, output_index: usize, salt: &Option<Vec<u8>>) -> [u8; BLAKE2B256_HASH_LEN]
 {
 	let mut blake2b = Blake2bBuilder::new(BLAKE2B256_HASH_LEN).personal(b"ckb-default-hash").build();
 
 	blake2b.update(&seed_cell_outpoint.tx_hash().raw_data());
 	blake2b.update(&seed_cell_outpoint.index().raw_data());
-	blake2b.update(&(output_index as u32).to_le_bytes());
+	match salt
+	{
+		Some(salt) => blake2b.update(salt),
+		None => blake2b.update(&(output_index as u32).to_le_bytes()),
+	}
 
 	// debug!("calc tx hash: {:?}", seed_cell_outpoint.tx_hash().raw_data());
 	// debug!("calc index: {:?}", seed_cell_outpoint.index().raw_data());
@@ -221,118 +1065,455 @@ fn collect_nft_indexes(script_hash: [u8; BLAKE2B256_HASH_LEN], source: Source) -
 	Ok(output_nft_indexes)
 }
 
-/// Collect all unique Instance IDs.
-fn collect_unique_instance_ids(nft_datas: &Vec<NftDataResolved>) -> BTreeSet<Vec<u8>>
+/// Collect all token logic code hashes which should be executed.
+fn collect_executable_token_logic_hashes(nft_data_sets: &Vec<&Vec<NftDataResolved>>) -> Result<BTreeSet<Vec<u8>>, Error>
+{
+	let mut token_logic_code_hashes = BTreeSet::new();
+
+	for nft_data_set in nft_data_sets.iter()
+	{
+		for nft_data in nft_data_set.iter()
+		{
+			// Do not include zero-filled hashes.
+			if nft_data.token_logic != CODE_HASH_NULL
+			{
+				token_logic_code_hashes.insert(nft_data.token_logic.clone());
+			}
+		}
+	}
+
+	Ok(token_logic_code_hashes)
+}
+
+/// Collect and parse all NftData from the specified source.
+fn collect_nft_data(source: Source) -> Result<Vec<NftDataResolved>, Error>
+{
+	let mut nft_datas = Vec::new();
+
+	// Load Cells until the end of the source is reached.
+	for index in 0..
+	{
+		let nft_data = match load_nft_data(index, source)
+		{
+			Ok(nft_data) => nft_data,
+			Err(Error::IndexOutOfBound) => break,
+			Err(err) => return Err(err),
+		};
+		validate_nft_data(&nft_data)?;
+
+		nft_datas.push(nft_data.into());
+	}
+
+	Ok(nft_datas)
+}
+
+/// Load the NftData of a Cell without copying the Custom field into memory.
+fn load_nft_data(index: usize, source: Source) -> Result<NftData, Error>
+{
+	// Load only the fixed-size header, which also reveals the full length of the Cell data.
+	let mut header = [0u8; NFT_HEADER_MAX_LEN];
+	let cell_data_len = match syscalls::load_cell_data(&mut header, 0, index, source)
+	{
+		Ok(len) => len,
+		Err(SysError::LengthNotEnough(len)) => len,
+		Err(err) => return Err(err.into()),
+	};
+	let header_len = cmp::min(cell_data_len, NFT_HEADER_MAX_LEN);
+	let mut nft_data = parse_nft_data(&header[0..header_len], cell_data_len)?;
+
+	// Digest the Custom field, which follows the header, if it exists.
+	if cell_data_len > nft_data.custom_offset
+	{
+		if nft_data.version == NFT_DATA_V2
+		{
+			verify_custom_v2(index, source, nft_data.custom_offset, cell_data_len)?;
+		}
+
+		nft_data.custom = Some(load_custom_digest(index, source, nft_data.custom_offset, cell_data_len)?);
+	}
+
+	Ok(nft_data)
+}
+
+/// Load an exact number of bytes of Cell data from the specified offset, or error if there are not enough bytes.
+fn load_cell_data_exact(buf: &mut [u8], offset: usize, index: usize, source: Source) -> Result<(), Error>
+{
+	match syscalls::load_cell_data(buf, offset, index, source)
+	{
+		Ok(len) if len == buf.len() => Ok(()),
+		Ok(_) => Err(Error::Encoding),
+		Err(SysError::LengthNotEnough(_)) => Ok(()),
+		Err(err) => Err(err.into()),
+	}
+}
+
+/// Verify the structure of a v2 Custom field without loading its content into memory.
+/// The Custom field is a Molecule table of two Bytes fields, so only the offsets and item counts need to be checked.
+fn verify_custom_v2(index: usize, source: Source, start: usize, end: usize) -> Result<(), Error>
+{
+	// Load the table header and ensure it describes exactly the remaining Cell data.
+	let mut header = [0u8; NUMBER_SIZE * (CustomReader::FIELD_COUNT + 1)];
+	load_cell_data_exact(&mut header, start, index, source)?;
+	let total_size = unpack_number(&header[0..]) as usize;
+	let content_type_offset = unpack_number(&header[NUMBER_SIZE..]) as usize;
+	let content_offset = unpack_number(&header[NUMBER_SIZE * 2..]) as usize;
+	if total_size != end - start || content_type_offset != header.len() || content_offset < content_type_offset + NUMBER_SIZE || total_size < content_offset + NUMBER_SIZE
+	{
+		return Err(Error::Encoding);
+	}
+
+	// Ensure the item count of each Bytes field matches its size.
+	for (field_start, field_end) in [(content_type_offset, content_offset), (content_offset, total_size)].iter()
+	{
+		let mut item_count = [0u8; NUMBER_SIZE];
+		load_cell_data_exact(&mut item_count, start + field_start, index, source)?;
+		if unpack_number(&item_count) as usize != field_end - field_start - NUMBER_SIZE
+		{
+			return Err(Error::Encoding);
+		}
+	}
+
+	Ok(())
+}
+
+/// Calculate the CustomDigest of a range of Cell data by loading it in fixed-size chunks.
+fn load_custom_digest(index: usize, source: Source, start: usize, end: usize) -> Result<CustomDigest, Error>
+{
+	let mut blake2b = Blake2bBuilder::new(BLAKE2B256_HASH_LEN).personal(b"ckb-default-hash").build();
+	let mut buf = [0u8; CUSTOM_CHUNK_LEN];
+
+	let mut offset = start;
+	while offset < end
+	{
+		// A partial load returns LengthNotEnough when more data remains beyond the buffer.
+		let len = match syscalls::load_cell_data(&mut buf, offset, index, source)
+		{
+			Ok(len) => len,
+			Err(SysError::LengthNotEnough(_)) => CUSTOM_CHUNK_LEN,
+			Err(err) => return Err(err.into()),
+		};
+		let len = cmp::min(len, end - offset);
+
+		blake2b.update(&buf[0..len]);
+		offset += len;
+	}
+
+	let mut hash: [u8; BLAKE2B256_HASH_LEN] = [0; BLAKE2B256_HASH_LEN];
+	blake2b.finalize(&mut hash);
+
+	Ok(CustomDigest { len: end - start, hash: hash })
+}
+
+/// Calculate the CustomDigest of all the data of a Cell by loading it in fixed-size chunks.
+fn load_cell_data_digest(index: usize, source: Source) -> Result<CustomDigest, Error>
+{
+	// An empty buffer loads nothing, but reveals the full length of the Cell data.
+	let cell_data_len = match syscalls::load_cell_data(&mut [], 0, index, source)
+	{
+		Ok(len) => len,
+		Err(SysError::LengthNotEnough(len)) => len,
+		Err(err) => return Err(err.into()),
+	};
+
+	load_custom_digest(index, source, 0, cell_data_len)
+}
+
+/// Add an NFT quantity to the running total of the specified key, or error if the total would overflow.
+fn collect_nft_quantity<K: Ord>(quantities: &mut BTreeMap<K, u128>, key: K, quantity: u128) -> Result<(), Error>
+{
+	let total = quantities.entry(key).or_insert(0u128);
+	*total = total.checked_add(quantity).ok_or(Error::QuantityOverflow)?;
+
+	Ok(())
+}
+
+/// Holds the total NFT quantities of a set of NftDataResolved, aggregated by Instance ID, by Instance ID and Token Logic, and by Instance ID and the pairing of Token Logic and Custom field digest.
+#[derive(Debug)]
+struct NftQuantities
 {
-	let mut instance_ids = BTreeSet::new();
+	by_instance_id: BTreeMap<Vec<u8>, u128>,
+	by_instance_id_and_token_logic: BTreeMap<(Vec<u8>, Vec<u8>), u128>,
+	by_instance_id_and_token_logic_and_custom: BTreeMap<(Vec<u8>, (Vec<u8>, CustomDigest)), u128>,
+}
+
+impl NftQuantities
+{
+	/// The total quantity for the Instance ID, or zero if it is not present.
+	fn instance_id_quantity(&self, instance_id: &Vec<u8>) -> u128
+	{
+		self.by_instance_id.get(instance_id).copied().unwrap_or(0)
+	}
+
+	/// The total quantity for the Instance ID and Token Logic pair, or zero if it is not present.
+	fn instance_id_and_token_logic_quantity(&self, instance_id: &Vec<u8>, token_logic: &Vec<u8>) -> u128
+	{
+		self.by_instance_id_and_token_logic.get(&(instance_id.clone(), token_logic.clone())).copied().unwrap_or(0)
+	}
+
+	/// The total quantity for the Instance ID and the pairing of Token Logic and Custom field digest, or zero if it is not present.
+	fn instance_id_and_token_logic_and_custom_quantity(&self, instance_id: &Vec<u8>, token_logic: &Vec<u8>, custom: &CustomDigest) -> u128
+	{
+		self.by_instance_id_and_token_logic_and_custom.get(&(instance_id.clone(), (token_logic.clone(), custom.clone()))).copied().unwrap_or(0)
+	}
+}
+
+/// Aggregate the NFT quantities of all NftDataResolved in a single pass.
+fn collect_nft_quantities(nft_datas: &Vec<NftDataResolved>) -> Result<NftQuantities, Error>
+{
+	let mut nft_quantities = NftQuantities
+	{
+		by_instance_id: BTreeMap::new(),
+		by_instance_id_and_token_logic: BTreeMap::new(),
+		by_instance_id_and_token_logic_and_custom: BTreeMap::new(),
+	};
+
+	for nft_data in nft_datas.iter()
+	{
+		collect_nft_quantity(&mut nft_quantities.by_instance_id, nft_data.instance_id.clone(), nft_data.quantity)?;
+		collect_nft_quantity(&mut nft_quantities.by_instance_id_and_token_logic, (nft_data.instance_id.clone(), nft_data.token_logic.clone()), nft_data.quantity)?;
+		collect_nft_quantity(&mut nft_quantities.by_instance_id_and_token_logic_and_custom, (nft_data.instance_id.clone(), (nft_data.token_logic.clone(), nft_data.custom.clone())), nft_data.quantity)?;
+	}
+
+	Ok(nft_quantities)
+}
+
+/// Collect the Serial number of each Instance ID of all NftDataResolved.
+/// Every Cell of an instance carries the same Serial number, so a conflict is invalid.
+fn collect_nft_serials(nft_datas: &Vec<NftDataResolved>) -> Result<BTreeMap<Vec<u8>, Option<u128>>, Error>
+{
+	let mut serials = BTreeMap::new();
 
 	for nft_data in nft_datas.iter()
 	{
-		// Extract the Instance ID from the NftData instance.
-		let instance_id = nft_data.instance_id.clone().into_iter().take(TOKEN_LOGIC_LEN).collect();
+		if let Some(serial) = serials.insert(nft_data.instance_id.clone(), nft_data.serial)
+		{
+			if serial != nft_data.serial
+			{
+				return Err(Error::InvalidSerial);
+			}
+		}
+	}
+
+	Ok(serials)
+}
+
+/// Aggregate the NFT quantities of all NftDataResolved by Instance ID and the Lock Script hash of the Cell containing it.
+fn collect_nft_quantities_by_lock_hash(nft_datas: &Vec<NftDataResolved>, source: Source) -> Result<BTreeMap<(Vec<u8>, [u8; LOCK_HASH_LEN]), u128>, Error>
+{
+	let mut quantities = BTreeMap::new();
+
+	for (nft_data, lock_hash) in nft_datas.iter().zip(QueryIter::new(load_cell_lock_hash, source))
+	{
+		collect_nft_quantity(&mut quantities, (nft_data.instance_id.clone(), lock_hash), nft_data.quantity)?;
+	}
+
+	Ok(quantities)
+}
+
+/// Aggregate the NFT quantities of all NftDataResolved by Instance ID and Custom field digest.
+fn collect_nft_quantities_by_custom(nft_datas: &Vec<NftDataResolved>) -> Result<BTreeMap<(Vec<u8>, CustomDigest), u128>, Error>
+{
+	let mut quantities = BTreeMap::new();
+
+	for nft_data in nft_datas.iter()
+	{
+		collect_nft_quantity(&mut quantities, (nft_data.instance_id.clone(), nft_data.custom.clone()), nft_data.quantity)?;
+	}
+
+	Ok(quantities)
+}
+
+/// Aggregate by Instance ID the amounts by which group output quantities exceed the group input quantities of the same key.
+fn collect_nft_quantity_increases<K: Ord>(input_quantities: &BTreeMap<(Vec<u8>, K), u128>, output_quantities: &BTreeMap<(Vec<u8>, K), u128>) -> Result<BTreeMap<Vec<u8>, u128>, Error>
+{
+	let mut increases = BTreeMap::new();
+
+	for (key, output_nft_quantity) in output_quantities.iter()
+	{
+		let input_nft_quantity = input_quantities.get(key).copied().unwrap_or(0);
+		if *output_nft_quantity > input_nft_quantity
+		{
+			collect_nft_quantity(&mut increases, key.0.clone(), output_nft_quantity - input_nft_quantity)?;
+		}
+	}
+
+	Ok(increases)
+}
+
+/// Collect the events of the script group with a non-zero quantity, keyed by event kind and Instance ID.
+fn collect_nft_events(group_input_nft_data: &Vec<NftDataResolved>, group_output_nft_data: &Vec<NftDataResolved>, group_input_quantities: &NftQuantities, group_output_quantities: &NftQuantities) -> Result<BTreeMap<(u8, Vec<u8>), u128>, Error>
+{
+	let mut events = BTreeMap::new();
+
+	// Instances with an increased total quantity were minted, and instances with a decreased total quantity were burned.
+	for (instance_id, output_nft_quantity) in group_output_quantities.by_instance_id.iter()
+	{
+		let input_nft_quantity = group_input_quantities.instance_id_quantity(instance_id);
+		if *output_nft_quantity > input_nft_quantity
+		{
+			events.insert((EVENT_MINT, instance_id.clone()), output_nft_quantity - input_nft_quantity);
+		}
+	}
+	for (instance_id, input_nft_quantity) in group_input_quantities.by_instance_id.iter()
+	{
+		let output_nft_quantity = group_output_quantities.instance_id_quantity(instance_id);
+		if *input_nft_quantity > output_nft_quantity
+		{
+			events.insert((EVENT_BURN, instance_id.clone()), input_nft_quantity - output_nft_quantity);
+		}
+	}
 
-		instance_ids.insert(instance_id);
+	// Quantities received by a lock, or by a Token Logic and Custom pairing, which did not hold them in the group input were transferred or updated.
+	// Minted quantities are always received this way, so they are excluded.
+	let transferred = collect_nft_quantity_increases(&collect_nft_quantities_by_lock_hash(group_input_nft_data, Source::GroupInput)?, &collect_nft_quantities_by_lock_hash(group_output_nft_data, Source::GroupOutput)?)?;
+	let updated = collect_nft_quantity_increases(&group_input_quantities.by_instance_id_and_token_logic_and_custom, &group_output_quantities.by_instance_id_and_token_logic_and_custom)?;
+	for (kind, increases) in [(EVENT_TRANSFER, transferred), (EVENT_UPDATE, updated)].iter()
+	{
+		for (instance_id, quantity) in increases.iter()
+		{
+			let minted_quantity = events.get(&(EVENT_MINT, instance_id.clone())).copied().unwrap_or(0);
+			if *quantity > minted_quantity
+			{
+				events.insert((*kind, instance_id.clone()), quantity - minted_quantity);
+			}
+		}
 	}
 
-	instance_ids
+	Ok(events)
 }
 
-/// Collect all token logic code hashes which should be executed.
-fn collect_executable_token_logic_hashes(nft_data_sets: &Vec<&Vec<NftDataResolved>>) -> Result<BTreeSet<Vec<u8>>, Error>
+/// Verify the event records in the NFT witness of the script group against the actual changes of the group input and group output.
+/// Every event must be recorded exactly once when the event records are included.
+fn verify_nft_events(group_input_nft_data: &Vec<NftDataResolved>, group_output_nft_data: &Vec<NftDataResolved>, group_input_quantities: &NftQuantities, group_output_quantities: &NftQuantities) -> Result<(), Error>
 {
-	let mut token_logic_code_hashes = BTreeSet::new();
+	let nft_events = match load_group_nft_witness()?.and_then(|x| x.events)
+	{
+		Some(nft_events) => nft_events,
+		None => return Ok(()),
+	};
 
-	for nft_data_set in nft_data_sets.iter()
+	// Each event record must have a known kind, a non-zero quantity, and a unique kind and Instance ID pairing.
+	let mut events = BTreeMap::new();
+	for nft_event in nft_events.iter()
 	{
-		for nft_data in nft_data_set.iter()
+		if nft_event.kind > EVENT_UPDATE || nft_event.quantity == 0 || events.insert((nft_event.kind, nft_event.instance_id.clone()), nft_event.quantity).is_some()
 		{
-			// Do not include zero-filled hashes.
-			if nft_data.token_logic != CODE_HASH_NULL
-			{
-				token_logic_code_hashes.insert(nft_data.token_logic.clone());
-			}
+			return Err(Error::InvalidEvent);
 		}
 	}
 
-	Ok(token_logic_code_hashes)
+	if events != collect_nft_events(group_input_nft_data, group_output_nft_data, group_input_quantities, group_output_quantities)?
+	{
+		return Err(Error::EventMismatch);
+	}
+
+	Ok(())
 }
 
-/// Collect and parse all NftData from the specified source.
-fn collect_nft_data(source: Source) -> Result<Vec<NftDataResolved>, Error>
+/// Load the minted count from the Collection Cell with the specified type hash in the specified source.
+fn load_collection_minted_count(collection_type_hash: &Vec<u8>, source: Source) -> Result<u128, Error>
 {
-	let parse_and_validate_nft_data = |x: Vec<u8>|
-	{
-		let nft_data = parse_nft_data(&x)?;
-		validate_nft_data(&nft_data)?;
+	let index = QueryIter::new(load_cell_type_hash, source)
+		.position(|type_hash| type_hash.map_or(false, |type_hash| collection_type_hash[..] == type_hash[..]))
+		.ok_or(Error::MissingCollectionCell)?;
 
-		Ok((&nft_data).into())
-	};
+	let cell_data = load_cell_data(index, source)?;
+	let collection_info = CollectionInfoReader::from_slice(&cell_data).or(Err(Error::Encoding))?;
+	let minted: [u8; U128_LEN] = collection_info.minted().raw_data().try_into().or(Err(Error::Encoding))?;
 
-	let nft_data: Result<Vec<NftDataResolved>, Error> = QueryIter::new(load_cell_data, source).map(|x|parse_and_validate_nft_data(x)).collect();
+	Ok(u128::from_le_bytes(minted))
+}
 
-	Ok(nft_data?)
+/// Determine if the Collection Cell with the specified type hash exists in the specified source.
+fn check_collection_cell(collection_type_hash: &Vec<u8>, source: Source) -> bool
+{
+	QueryIter::new(load_cell_type_hash, source)
+		.any(|type_hash| type_hash.map_or(false, |type_hash| collection_type_hash[..] == type_hash[..]))
 }
 
-/// Collect the NFT quantity from the matching Instance ID and token logic value only if included.
-fn collect_nft_quantity(instance_id: &Vec<u8>, token_logic: &Option<Vec<u8>>, nft_datas: &Vec<NftDataResolved>) -> Result<u128, Error>
+/// Verify that the Collection Cell was consumed and its minted count was increased by the number of generated instances.
+fn verify_collection_minted_count(collection_type_hash: &Vec<u8>, generated_count: u128) -> Result<(), Error>
 {
-	let mut quantity = 0u128;
-	let token_logic_exists = token_logic.is_some();
-	let token_logic = token_logic.clone().unwrap_or(vec!());
+	let input_minted = load_collection_minted_count(collection_type_hash, Source::Input)?;
+	let output_minted = load_collection_minted_count(collection_type_hash, Source::Output)?;
 
-	for nft_data in nft_datas.iter()
+	let expected_minted = input_minted.checked_add(generated_count).ok_or(Error::QuantityOverflow)?;
+	if output_minted != expected_minted
 	{
-		if &nft_data.instance_id == instance_id
-		{
-			if !token_logic_exists || nft_data.token_logic == token_logic
-			{
-				quantity += nft_data.quantity;
-			}
-		}
+		return Err(Error::InvalidCollectionMintedCount);
 	}
 
-	Ok(quantity)
+	Ok(())
 }
 
-/// Collect the quantities of the match NFT tokens group input and group output.
-fn collect_nft_quantities(nft_data: &NftDataResolved, group_input_nft_data: &Vec<NftDataResolved>, group_output_nft_data: &Vec<NftDataResolved>, consider_token_logic: bool) -> Result<(u128, u128), Error>
+/// Verify that an operation intent declared in an NFT witness matches the inferred operation.
+fn check_operation_intent(intent: Option<u8>, operation: u8) -> Result<(), Error>
 {
-	let instance_id = &nft_data.instance_id;
-	let token_logic = if consider_token_logic { Some(nft_data.token_logic.clone()) } else { None };
-
-	let group_input_quantity = collect_nft_quantity(instance_id, &token_logic, group_input_nft_data)?;
-	let group_output_quantity = collect_nft_quantity(instance_id, &token_logic, group_output_nft_data)?;
-
-	Ok((group_input_quantity, group_output_quantity))
+	match intent
+	{
+		Some(intent) if intent > OPERATION_REISSUE => Err(Error::InvalidOperationIntent),
+		Some(intent) if intent != operation => Err(Error::OperationIntentMismatch),
+		_ => Ok(()),
+	}
 }
 
-/// Check for data modifications within a Vec<NftData> where the Instance ID and Token Logic match.
-fn count_nft_data_modifications(nft_data: &NftDataResolved, group_nft_data: &Vec<NftDataResolved>) -> Result<usize, Error>
+/// Verify the operation intents declared in the NFT witnesses of the group input and group output against the inferred operations.
+/// Cells without a declared intent are not checked.
+fn verify_operation_intents(group_input_nft_data: &Vec<NftDataResolved>, group_output_nft_data: &Vec<NftDataResolved>, group_input_quantities: &NftQuantities, group_output_quantities: &NftQuantities, group_input_nft_witnesses: &Vec<Option<NftWitness>>, group_output_nft_witnesses: &Vec<Option<NftWitness>>) -> Result<(), Error>
 {
-	let mut modifications = 0;
+	for (input_nft_data, nft_witness) in group_input_nft_data.iter().zip(group_input_nft_witnesses.iter())
+	{
+		let intent = nft_witness.as_ref().and_then(|x| x.intent);
+
+		// An input is burned when its instance has a reduced quantity in the group output.
+		let input_nft_quantity = group_input_quantities.instance_id_quantity(&input_nft_data.instance_id);
+		let output_nft_quantity = group_output_quantities.instance_id_quantity(&input_nft_data.instance_id);
+		let operation = if output_nft_quantity < input_nft_quantity { OPERATION_BURN } else { OPERATION_TRANSFER };
+
+		check_operation_intent(intent, operation)?;
+	}
 
-	for input_nft_data in group_nft_data.iter()
+	for (output_nft_data, nft_witness) in group_output_nft_data.iter().zip(group_output_nft_witnesses.iter())
 	{
-		if nft_data.instance_id == input_nft_data.instance_id && nft_data.token_logic == input_nft_data.token_logic && nft_data.custom != input_nft_data.custom
+		let intent = nft_witness.as_ref().and_then(|x| x.intent);
+
+		// An output is reissued when the total quantity of its instance increases, and updated when no input of the same instance has the same Token Logic and Custom.
+		let operation = if !group_input_quantities.by_instance_id.contains_key(&output_nft_data.instance_id)
+		{
+			OPERATION_GENERATE
+		}
+		else if group_output_quantities.instance_id_quantity(&output_nft_data.instance_id) > group_input_quantities.instance_id_quantity(&output_nft_data.instance_id)
 		{
-			modifications += 1;
+			OPERATION_REISSUE
 		}
+		else if group_input_quantities.by_instance_id_and_token_logic_and_custom.contains_key(&(output_nft_data.instance_id.clone(), (output_nft_data.token_logic.clone(), output_nft_data.custom.clone())))
+		{
+			OPERATION_TRANSFER
+		}
+		else
+		{
+			OPERATION_UPDATE
+		};
+
+		check_operation_intent(intent, operation)?;
 	}
 
-	Ok(modifications)
+	Ok(())
 }
 
-/// Execute the token logic in a Cell with the specified code hash.
-fn execute_token_logic(token_logic_code_hash: &Vec<u8>) -> Result<(), Error>
+/// Execute the specified function of the token logic in a Cell with the specified code hash.
+/// A Token Logic without the mint function fails with a distinct error, since the function is optional unless the args enable it.
+fn execute_token_logic(token_logic_code_hash: &Vec<u8>, function: &[u8]) -> Result<(), Error>
 {
 	let token_logic_code_hash: [u8; TOKEN_LOGIC_LEN] = token_logic_code_hash.as_slice().try_into().expect("Conversion failed");
+	let missing_function_error = if function == TOKEN_LOGIC_MINT_FUNCTION { Error::MissingTokenLogicMintFunction } else { Error::MissingTokenLogicFunction };
 
 	let mut context = CKBDLContext::<[u8; CKBDL_CONTEXT_SIZE]>::new();
 	let lib = context.load(&token_logic_code_hash).or(Err(Error::MissingTokenLogicCellDep))?;
 	unsafe
 	{
 		type TokenLogic = unsafe extern "C" fn(token_logic_code_hash: &[u8; TOKEN_LOGIC_LEN]) -> i32;
-		let token_logic: Symbol<TokenLogic> = lib.get(TOKEN_LOGIC_FUNCTION).ok_or(Error::MissingTokenLogicFunction)?;
+		let token_logic: Symbol<TokenLogic> = lib.get(function).ok_or(missing_function_error)?;
 		let token_logic_error_code = token_logic(&token_logic_code_hash);
 
 		if token_logic_error_code != 0
@@ -371,11 +1552,35 @@ fn validate_token_logic(token_logic_code_hash: &Vec<u8>) -> Result<(), Error>
 	Ok(())
 }
 
-/// Parse Cell data into an NftData instance.
-fn parse_nft_data(cell_data: &Vec<u8>) -> Result<NftData, Error>
+/// Determine the layout version of NFT data from its header.
+/// Data which does not begin with the version marker uses the original unversioned layout.
+fn detect_nft_data_version(cell_data: &[u8]) -> Option<u8>
+{
+	if cell_data.len() >= NFT_DATA_MAGIC_LEN + NFT_DATA_VERSION_LEN && cell_data[0..NFT_DATA_MAGIC_LEN] == NFT_DATA_MAGIC
+	{
+		return Some(cell_data[NFT_DATA_MAGIC_LEN]);
+	}
+
+	None
+}
+
+/// Parse the header of Cell data into an NftData instance using the layout of the detected version.
+/// The Custom field is not included in the header and must be populated separately.
+fn parse_nft_data(cell_data: &[u8], cell_data_len: usize) -> Result<NftData, Error>
 {
-	let cell_data_len = cell_data.len();
+	match detect_nft_data_version(cell_data)
+	{
+		None => parse_nft_data_v0(cell_data, cell_data_len),
+		Some(NFT_DATA_V1) => parse_nft_data_v1(cell_data, cell_data_len),
+		Some(NFT_DATA_V2) => parse_nft_data_v2(cell_data, cell_data_len),
+		Some(_) => Err(Error::UnsupportedVersion),
+	}
+}
 
+/// Parse the header of v0 Cell data into an NftData instance.
+/// Layout: Instance ID | Quantity | Token Logic | Custom
+fn parse_nft_data_v0(cell_data: &[u8], cell_data_len: usize) -> Result<NftData, Error>
+{
 	// Extract Instance ID value or error if there are not enough bytes.
 	if cell_data_len < INSTANCE_ID_LEN
 	{
@@ -413,29 +1618,182 @@ fn parse_nft_data(cell_data: &Vec<u8>) -> Result<NftData, Error>
 		token_logic = Some(cell_data[start..end].to_vec())
 	}
 
-	// Extract the Custom field if it exists.
-	let mut custom = None;
-	if cell_data_len > INSTANCE_ID_LEN + QUANTITY_LEN + TOKEN_LOGIC_LEN
+	// Create the NftData instance.
+	let nft_data = NftData
+	{
+		version: NFT_DATA_V0,
+		flags: 0,
+		instance_id: instance_id,
+		quantity: quantity,
+		token_logic: token_logic,
+		custom: None,
+		custom_offset: NFT_HEADER_V0_LEN,
+		serial: None,
+	};
+
+	Ok(nft_data)
+}
+
+/// Parse the header of v1 Cell data into an NftData instance.
+/// Layout: Magic | Version | Flags | Instance ID | Quantity | Token Logic | Custom
+/// All fields except for Custom are required.
+fn parse_nft_data_v1(cell_data: &[u8], cell_data_len: usize) -> Result<NftData, Error>
+{
+	// Extract the Flags field or error if there are not enough bytes.
+	if cell_data_len < NFT_DATA_PREFIX_LEN
+	{
+		return Err(Error::InvalidStructure);
+	}
+	let flags = cell_data[NFT_DATA_MAGIC_LEN + NFT_DATA_VERSION_LEN];
+
+	// Extract Instance ID value or error if there are not enough bytes.
+	let start = NFT_DATA_PREFIX_LEN;
+	let end = start + INSTANCE_ID_LEN;
+	if cell_data_len < end
+	{
+		return Err(Error::InvalidInstanceIdLength);
+	}
+	let instance_id = cell_data[start..end].to_vec();
+
+	// Extract the Quantity field or error if there are not enough bytes.
+	let start = end;
+	let end = start + QUANTITY_LEN;
+	if cell_data_len < end
+	{
+		return Err(Error::InvalidQuantityLength);
+	}
+	let mut buf = [0u8; QUANTITY_LEN];
+	buf.copy_from_slice(&cell_data[start..end]);
+	let quantity = u128::from_le_bytes(buf);
+
+	// Extract the Token Logic field or error if there are not enough bytes.
+	let start = end;
+	let end = start + TOKEN_LOGIC_LEN;
+	if cell_data_len < end
+	{
+		return Err(Error::InvalidTokenLogicLength);
+	}
+	let token_logic = cell_data[start..end].to_vec();
+
+	// Create the NftData instance.
+	let nft_data = NftData
+	{
+		version: NFT_DATA_V1,
+		flags: flags,
+		instance_id: instance_id,
+		quantity: Some(quantity),
+		token_logic: Some(token_logic),
+		custom: None,
+		custom_offset: NFT_HEADER_V1_LEN,
+		serial: None,
+	};
+
+	Ok(nft_data)
+}
+
+/// Parse the header of v2 Cell data into an NftData instance.
+/// Layout: Magic | Version | Flags | Serial (optional) | NftData (Molecule, see schemas/nft.mol)
+/// Every field of the NftData table except for Custom is contained in the header.
+fn parse_nft_data_v2(cell_data: &[u8], cell_data_len: usize) -> Result<NftData, Error>
+{
+	// Extract the Flags field or error if there are not enough bytes.
+	if cell_data_len < NFT_DATA_PREFIX_LEN
+	{
+		return Err(Error::InvalidStructure);
+	}
+	let flags = cell_data[NFT_DATA_MAGIC_LEN + NFT_DATA_VERSION_LEN];
+
+	// Extract the Serial field if it is flagged as present, or error if there are not enough bytes.
+	let mut prefix_len = NFT_DATA_PREFIX_LEN;
+	let mut serial = None;
+	if flags & NFT_DATA_V2_FLAG_SERIAL != 0
+	{
+		if cell_data_len < NFT_DATA_PREFIX_LEN + SERIAL_LEN
+		{
+			return Err(Error::InvalidStructure);
+		}
+
+		let mut buf = [0u8; SERIAL_LEN];
+		buf.copy_from_slice(&cell_data[NFT_DATA_PREFIX_LEN..NFT_DATA_PREFIX_LEN + SERIAL_LEN]);
+		serial = Some(u128::from_le_bytes(buf));
+		prefix_len += SERIAL_LEN;
+	}
+
+	// Ensure the table header describes exactly the remaining Cell data and has the expected number of fields.
+	let table = &cell_data[prefix_len..];
+	let table_len = cell_data_len - prefix_len;
+	if table.len() < NFT_DATA_V2_TABLE_HEADER_LEN || unpack_number(table) as usize != table_len || unpack_number(&table[NUMBER_SIZE..]) as usize != NFT_DATA_V2_TABLE_HEADER_LEN
+	{
+		return Err(Error::Encoding);
+	}
+
+	// Extract the field offsets, which must be in ascending order.
+	let mut offsets = [table_len; NftDataReader::FIELD_COUNT + 1];
+	for (field_index, offset) in offsets.iter_mut().take(NftDataReader::FIELD_COUNT).enumerate()
+	{
+		*offset = unpack_number(&table[NUMBER_SIZE * (field_index + 1)..]) as usize;
+	}
+	if offsets.windows(2).any(|x| x[0] > x[1])
+	{
+		return Err(Error::Encoding);
+	}
+
+	// Ensure that all fields preceding Custom are contained in the header.
+	let custom_offset = offsets[NftDataReader::FIELD_COUNT - 1];
+	if table.len() < custom_offset
 	{
-		let start = INSTANCE_ID_LEN + QUANTITY_LEN + TOKEN_LOGIC_LEN;
-		let end = cell_data_len;
-		custom = Some(cell_data[start..end].to_vec())
+		return Err(Error::Encoding);
 	}
 
+	// Extract the Instance ID field.
+	let instance_id = &table[offsets[0]..offsets[1]];
+	Byte32Reader::verify(instance_id, false).or(Err(Error::InvalidInstanceIdLength))?;
+	let instance_id = instance_id.to_vec();
+
+	// Extract the Quantity field if it exists.
+	let quantity = &table[offsets[1]..offsets[2]];
+	Uint128OptReader::verify(quantity, false).or(Err(Error::InvalidQuantityLength))?;
+	let quantity = Uint128OptReader::new_unchecked(quantity).to_opt().map(|x|
+	{
+		let mut buf = [0u8; QUANTITY_LEN];
+		buf.copy_from_slice(x.raw_data());
+		u128::from_le_bytes(buf)
+	});
+
+	// Extract the Token Logic field if it exists.
+	let token_logic = &table[offsets[2]..offsets[3]];
+	Byte32OptReader::verify(token_logic, false).or(Err(Error::InvalidTokenLogicLength))?;
+	let token_logic = Byte32OptReader::new_unchecked(token_logic).to_opt().map(|x| x.raw_data().to_vec());
+
 	// Create the NftData instance.
 	let nft_data = NftData
 	{
+		version: NFT_DATA_V2,
+		flags: flags,
 		instance_id: instance_id,
 		quantity: quantity,
 		token_logic: token_logic,
-		custom: custom,
+		custom: None,
+		custom_offset: prefix_len + custom_offset,
+		serial: serial,
 	};
 
 	Ok(nft_data)
 }
 
-// Validate the data in an NftData instance.
+// Validate the data in an NftData instance using the rules of its version.
 fn validate_nft_data(nft_data: &NftData) -> Result<(), Error>
+{
+	match nft_data.version
+	{
+		NFT_DATA_V1 => validate_nft_data_v1(nft_data),
+		NFT_DATA_V2 => validate_nft_data_v2(nft_data),
+		_ => validate_nft_data_v0(nft_data),
+	}
+}
+
+// Validate the data in a v0 NftData instance.
+fn validate_nft_data_v0(nft_data: &NftData) -> Result<(), Error>
 {
 	// Ensure that the Instance ID field is the correct length.
 	if nft_data.instance_id.len() != INSTANCE_ID_LEN
@@ -466,21 +1824,72 @@ fn validate_nft_data(nft_data: &NftData) -> Result<(), Error>
 	Ok(())
 }
 
-fn main() -> Result<(), Error>
+// Validate the data in a v1 NftData instance.
+fn validate_nft_data_v1(nft_data: &NftData) -> Result<(), Error>
 {
-	// Load arguments from the current script.
-	let script = load_script()?;
-	let args = script.args();
+	// Ensure that no reserved flags are set.
+	if nft_data.flags & !NFT_DATA_V1_FLAGS_MASK != 0
+	{
+		return Err(Error::InvalidFlags);
+	}
 
-	// Verify that the minimum length of the arguments was given.
-	if args.len() < ARGS_LEN
+	// Ensure that the Instance ID field is the correct length.
+	if nft_data.instance_id.len() != INSTANCE_ID_LEN
 	{
-		return Err(Error::InvalidArgsLen);
+		return Err(Error::InvalidInstanceIdLength);
+	}
+
+	// Ensure that the Quantity and Token Logic fields are always present.
+	if nft_data.quantity.is_none() || nft_data.token_logic.is_none()
+	{
+		return Err(Error::InvalidStructure);
+	}
+
+	// Ensure that the Token Logic field is the correct length.
+	if nft_data.token_logic.as_ref().unwrap().len() != TOKEN_LOGIC_LEN
+	{
+		return Err(Error::InvalidTokenLogicLength);
+	}
+
+	Ok(())
+}
+
+// Validate the data in a v2 NftData instance.
+fn validate_nft_data_v2(nft_data: &NftData) -> Result<(), Error>
+{
+	// Ensure that no reserved flags are set.
+	if nft_data.flags & !NFT_DATA_V2_FLAGS_MASK != 0
+	{
+		return Err(Error::InvalidFlags);
+	}
+
+	// Ensure that the Instance ID field is the correct length.
+	if nft_data.instance_id.len() != INSTANCE_ID_LEN
+	{
+		return Err(Error::InvalidInstanceIdLength);
 	}
 
+	// Ensure that the Token Logic field is the correct length if it exists.
+	// Optional fields are encoded independently, so any combination of them is a valid structure.
+	if nft_data.token_logic.is_some() && nft_data.token_logic.as_ref().unwrap().len() != TOKEN_LOGIC_LEN
+	{
+		return Err(Error::InvalidTokenLogicLength);
+	}
+
+	Ok(())
+}
+
+fn main() -> Result<(), Error>
+{
+	// Load and parse arguments from the current script.
+	let script = load_script()?;
+	let args = parse_args(&script.args())?;
+
 	// Detect owner mode.
 	let owner_mode = check_owner_mode(&args)?;
 	// debug!("Owner Mode: {}", owner_mode);
+	let minter_mode = check_minter_mode(&args, owner_mode)?;
+	// debug!("Minter Mode: {}", minter_mode);
 
 	// Collect group input and group output Cells.
 	// let group_input_cells: Vec<Cell> = QueryIter::new(load_cell, Source::GroupInput).collect();
@@ -492,8 +1901,22 @@ fn main() -> Result<(), Error>
 	let group_input_nft_data = collect_nft_data(Source::GroupInput)?;
 	let group_output_nft_data = collect_nft_data(Source::GroupOutput)?;
 
-	// Locate all unique group input Instance IDs.
-	let group_input_instance_ids = collect_unique_instance_ids(&group_input_nft_data);
+	// Aggregate the quantities of the group input and group output.
+	let group_input_quantities = collect_nft_quantities(&group_input_nft_data)?;
+	let group_output_quantities = collect_nft_quantities(&group_output_nft_data)?;
+
+	// Collect the Serial number of each Instance ID in the group input.
+	let group_input_serials = collect_nft_serials(&group_input_nft_data)?;
+
+	// Parse the NFT witness of each group input and group output once, since they are used by several checks.
+	let group_input_nft_witnesses = collect_nft_witnesses(group_input_nft_data.len(), Source::GroupInput)?;
+	let group_output_nft_witnesses = collect_nft_witnesses(group_output_nft_data.len(), Source::GroupOutput)?;
+
+	// Verify the operation intents declared in the NFT witnesses before any other checks so a malformed transaction fails with a precise error.
+	verify_operation_intents(&group_input_nft_data, &group_output_nft_data, &group_input_quantities, &group_output_quantities, &group_input_nft_witnesses, &group_output_nft_witnesses)?;
+
+	// Verify the event records declared in the NFT witness so indexers can rely on them.
+	verify_nft_events(&group_input_nft_data, &group_output_nft_data, &group_input_quantities, &group_output_quantities)?;
 
 	// Locate the index of all output NFTs.
 	let script_hash = load_script_hash()?;
@@ -506,32 +1929,78 @@ fn main() -> Result<(), Error>
 		return Err(Error::UnexpectedCellMismatch);
 	}
 
-	// Determine the Seed Cell Outpoint.
-	let seed_cell_outpoint = load_input(0, Source::Input)?.previous_output();
+	// The Seed Cell Outpoint is determined by the first generation operation, so transactions without generation never read the seed index.
+	let mut seed_cell_outpoint = None;
+
+	// Collect the salts of generated instances, since the same salt and Seed Cell always result in the same Instance ID.
+	let mut generated_salts = BTreeSet::new();
 
 	// Collect unique Token Logic code hashes which will be executed or validated.
 	let mut token_logic_code_hashes_execute = BTreeSet::new();
 	let mut token_logic_code_hashes_validate = BTreeSet::new();
+	let mut token_logic_code_hashes_execute_mint = BTreeSet::new();
+
+	// Collect the quantities which may be generated without minter mode from allowlist claims.
+	let mut allowlist_allowances = collect_allowlist_allowances(&args)?;
+
+	// Count the instances created by generation operations and by paid minting, and collect their Serial numbers in output order.
+	let mut generated_count: u128 = 0;
+	let mut paid_generated_count: u128 = 0;
+	let mut generated_serials = vec!();
 
 	// Loop through all group output NFTData.
 	for (index, output_nft_data) in group_output_nft_data.iter().enumerate()
 	{
+		// Enforce a quantity of exactly one for each Instance ID when strict non-fungible mode is enabled.
+		// Checking the total across the group output prevents splitting an instance into multiple Cells.
+		if args.flags & ARGS_FLAG_NON_FUNGIBLE != 0
+		{
+			if output_nft_data.quantity != 1 || group_output_quantities.instance_id_quantity(&output_nft_data.instance_id) != 1
+			{
+				return Err(Error::NonFungibleQuantity);
+			}
+		}
+
+		// Ensure a Serial number is present only when the collection uses them.
+		if (args.flags & ARGS_FLAG_SERIAL != 0) != output_nft_data.serial.is_some()
+		{
+			return Err(Error::InvalidSerial);
+		}
+
 		// If the Instance ID is included it is a transfer/upgrade/burn operation, otherwise it is a generation operation.
-		if group_input_instance_ids.contains(&output_nft_data.instance_id)
+		if group_input_quantities.by_instance_id.contains_key(&output_nft_data.instance_id)
 		{
 			// debug!("Operation: Transfer/Update/Burn");
 
 			// Validate quantities using only Instance ID.
-			let (input_nft_quantity, output_nft_quantity) = collect_nft_quantities(&output_nft_data, &group_input_nft_data, &group_output_nft_data, false)?;
+			let input_nft_quantity = group_input_quantities.instance_id_quantity(&output_nft_data.instance_id);
+			let output_nft_quantity = group_output_quantities.instance_id_quantity(&output_nft_data.instance_id);
 			if output_nft_quantity > input_nft_quantity
 			{
-				return Err(Error::InvalidQuantity);
+				// Reissuing additional quantity of an existing instance is only allowed in owner mode when it is enabled by the args.
+				if !owner_mode || args.extended_flags & ARGS_EXTENDED_FLAG_REISSUE == 0
+				{
+					return Err(Error::InvalidQuantity);
+				}
+
+				// Every group output of a reissued instance must explicitly declare the reissue intent, so an increase is never implicit.
+				if group_output_nft_witnesses[index].as_ref().and_then(|x| x.intent) != Some(OPERATION_REISSUE)
+				{
+					return Err(Error::InvalidQuantity);
+				}
+			}
+
+			// Ensure the Serial number of the instance is never changed.
+			if group_input_serials.get(&output_nft_data.instance_id) != Some(&output_nft_data.serial)
+			{
+				return Err(Error::InvalidSerial);
 			}
 
 			// Check for an unauthorized operation which changes a Token Logic if not owner.
 			if !owner_mode
 			{
-				let (input_nft_quantity, output_nft_quantity) = collect_nft_quantities(&output_nft_data, &group_input_nft_data, &group_output_nft_data, true)?;
+				let input_nft_quantity = group_input_quantities.instance_id_and_token_logic_quantity(&output_nft_data.instance_id, &output_nft_data.token_logic);
+				let output_nft_quantity = group_output_quantities.instance_id_and_token_logic_quantity(&output_nft_data.instance_id, &output_nft_data.token_logic);
 				if output_nft_quantity > input_nft_quantity
 				{
 					return Err(Error::UnauthorizedOperation);
@@ -544,7 +2013,10 @@ fn main() -> Result<(), Error>
 				let token_logic_code_hash = output_nft_data.token_logic.clone();
 				if token_logic_code_hash != CODE_HASH_NULL
 				{
-					if owner_mode || count_nft_data_modifications(&output_nft_data, &group_input_nft_data)? == 0
+					// The Custom field is modified when part of the group input quantity with the same Instance ID and Token Logic has a different Custom field.
+					let input_nft_quantity = group_input_quantities.instance_id_and_token_logic_quantity(&output_nft_data.instance_id, &output_nft_data.token_logic);
+					let unmodified_nft_quantity = group_input_quantities.instance_id_and_token_logic_and_custom_quantity(&output_nft_data.instance_id, &output_nft_data.token_logic, &output_nft_data.custom);
+					if owner_mode || input_nft_quantity == unmodified_nft_quantity
 					{
 						token_logic_code_hashes_validate.insert(token_logic_code_hash);
 					}
@@ -559,12 +2031,30 @@ fn main() -> Result<(), Error>
 		{
 			// debug!("Operation: Generate");
 
-			if !owner_mode
+			if !minter_mode && !check_mint_voucher(&args, &script_hash, index, &output_nft_data.instance_id, &group_output_nft_witnesses[index])? && !check_allowlist_allowance(&mut allowlist_allowances, index, output_nft_data.quantity)?
 			{
-				return Err(Error::UnauthorizedOperation);
+				// Paid minting allows anyone to generate, and the payment is verified once all instances are counted.
+				match &args.governance
+				{
+					Governance::PaidMint { .. } => paid_generated_count += 1,
+					_ => return Err(Error::UnauthorizedOperation),
+				}
 			}
 
-			let instance_id = calculate_instance_id(&seed_cell_outpoint, output_nft_indexes[index]);
+			// The salt is read from the NFT witness of the group output.
+			let salt = group_output_nft_witnesses[index].as_ref().and_then(|x| x.salt.clone());
+			if let Some(salt) = &salt
+			{
+				if !generated_salts.insert(salt.clone())
+				{
+					return Err(Error::DuplicateSalt);
+				}
+			}
+			if seed_cell_outpoint.is_none()
+			{
+				seed_cell_outpoint = Some(load_seed_cell_outpoint(&group_output_nft_witnesses[0])?);
+			}
+			let instance_id = calculate_instance_id(seed_cell_outpoint.as_ref().expect("Seed Cell Outpoint not loaded"), output_nft_indexes[index], &salt);
 			// debug!("Output Instance ID: {:?}", output_nft_data.instance_id);
 			// debug!("Calculated Instance ID: {:?}", instance_id);
 
@@ -575,16 +2065,118 @@ fn main() -> Result<(), Error>
 
 			if output_nft_data.token_logic != CODE_HASH_NULL
 			{
-				validate_token_logic(&output_nft_data.token_logic)?;
+				// The mint function is executed instead when enabled by the args, allowing the Token Logic to enforce constraints on generation.
+				if args.extended_flags & ARGS_EXTENDED_FLAG_MINT_TOKEN_LOGIC != 0
+				{
+					token_logic_code_hashes_execute_mint.insert(output_nft_data.token_logic.clone());
+				}
+				else
+				{
+					validate_token_logic(&output_nft_data.token_logic)?;
+				}
+			}
+
+			generated_count += 1;
+			generated_serials.push(output_nft_data.serial);
+		}
+	}
+
+	// Check that the Collection Cell was updated with the generated instances.
+	// This also applies when the Collection Cell is updated without generating, so the minted count cannot be increased to skip Serial numbers.
+	if let Some(collection_type_hash) = &args.collection_type_hash
+	{
+		if generated_count > 0 || (check_collection_cell(collection_type_hash, Source::Input) && check_collection_cell(collection_type_hash, Source::Output))
+		{
+			verify_collection_minted_count(collection_type_hash, generated_count)?;
+
+			// Ensure generated instances are numbered sequentially, starting after the previous minted count.
+			if args.flags & ARGS_FLAG_SERIAL != 0
+			{
+				let input_minted = load_collection_minted_count(collection_type_hash, Source::Input)?;
+				for (generated_index, serial) in generated_serials.iter().enumerate()
+				{
+					if *serial != Some(input_minted + generated_index as u128 + 1)
+					{
+						return Err(Error::InvalidSerial);
+					}
+				}
+			}
+		}
+	}
+
+	// Check that the price of the instances generated by paid minting was paid to the governance lock.
+	if paid_generated_count > 0
+	{
+		verify_paid_mint_payment(&args, &script_hash, paid_generated_count)?;
+	}
+
+	// Check for an unauthorized operation which moves a soulbound NFT to a different lock if not owner.
+	// Comparing quantities by lock allows splits, merges, and burns which remain under the same lock.
+	// Generated instances are skipped since they have no previous lock, and their generation has already been authorized.
+	if args.flags & ARGS_FLAG_SOULBOUND != 0 && !owner_mode
+	{
+		let group_input_lock_quantities = collect_nft_quantities_by_lock_hash(&group_input_nft_data, Source::GroupInput)?;
+		let group_output_lock_quantities = collect_nft_quantities_by_lock_hash(&group_output_nft_data, Source::GroupOutput)?;
+
+		for (instance_id_and_lock_hash, output_nft_quantity) in group_output_lock_quantities.iter()
+		{
+			if !group_input_quantities.by_instance_id.contains_key(&instance_id_and_lock_hash.0)
+			{
+				continue;
+			}
+
+			let input_nft_quantity = group_input_lock_quantities.get(instance_id_and_lock_hash).copied().unwrap_or(0);
+			if *output_nft_quantity > input_nft_quantity
+			{
+				return Err(Error::NonTransferable);
+			}
+		}
+	}
+
+	// Check for an unauthorized operation which burns all or part of an NFT if not owner.
+	// Instances in the group input which are missing from the group output or have a reduced quantity were burned.
+	if args.flags & ARGS_FLAG_NO_HOLDER_BURN != 0 && !owner_mode
+	{
+		for (instance_id, input_nft_quantity) in group_input_quantities.by_instance_id.iter()
+		{
+			let output_nft_quantity = group_output_quantities.by_instance_id.get(instance_id);
+			let is_burned = output_nft_quantity.map_or(true, |output_nft_quantity| output_nft_quantity < input_nft_quantity);
+			if is_burned
+			{
+				return Err(Error::UnauthorizedBurn);
 			}
 		}
 	}
 
-	// Collect all unique executable token logic code hashes from the group input if not owner mode.
-	// if !owner_mode
-	// {
-	// 	token_logic_code_hashes_execute.append(&mut collect_executable_token_logic_hashes(&vec!(&group_input_nft_data))?);
-	// }
+	// Check for an unauthorized operation which changes the Custom field of an immutable collection if not owner.
+	// Every output Instance ID and Custom pairing must exist in the input with at least the same quantity, so splits and merges cannot rewrite any portion.
+	// Generated instances are skipped since their initial Custom field is set by the authorized generation.
+	if args.flags & ARGS_FLAG_IMMUTABLE_CUSTOM != 0 && !owner_mode
+	{
+		let group_input_custom_quantities = collect_nft_quantities_by_custom(&group_input_nft_data)?;
+		let group_output_custom_quantities = collect_nft_quantities_by_custom(&group_output_nft_data)?;
+
+		for (instance_id_and_custom, output_nft_quantity) in group_output_custom_quantities.iter()
+		{
+			if !group_input_quantities.by_instance_id.contains_key(&instance_id_and_custom.0)
+			{
+				continue;
+			}
+
+			let is_modified = group_input_custom_quantities.get(instance_id_and_custom).map_or(true, |input_nft_quantity| output_nft_quantity > input_nft_quantity);
+			if is_modified
+			{
+				return Err(Error::ImmutableCustom);
+			}
+		}
+	}
+
+	// Collect all unique executable token logic code hashes from the group input if not owner mode and enabled by the args.
+	// This allows Token Logic to restrict transfers and burns which do not modify the NFT data.
+	if !owner_mode && args.extended_flags & ARGS_EXTENDED_FLAG_INPUT_TOKEN_LOGIC != 0
+	{
+		token_logic_code_hashes_execute.append(&mut collect_executable_token_logic_hashes(&vec!(&group_input_nft_data))?);
+	}
 
 	// Remove any duplicates in validate and execute, favoring execution.
 	for token_logic_code_hash in token_logic_code_hashes_execute.iter()
@@ -601,7 +2193,13 @@ fn main() -> Result<(), Error>
 	// Execute Token Logic.
 	for token_logic_code_hash in token_logic_code_hashes_execute.iter()
 	{
-		execute_token_logic(token_logic_code_hash)?;
+		execute_token_logic(token_logic_code_hash, TOKEN_LOGIC_FUNCTION)?;
+	}
+
+	// Execute the mint function of Token Logic for generated instances.
+	for token_logic_code_hash in token_logic_code_hashes_execute_mint.iter()
+	{
+		execute_token_logic(token_logic_code_hash, TOKEN_LOGIC_MINT_FUNCTION)?;
 	}
 
 	Ok(())
@@ -0,0 +1,5 @@
+//! Generated Molecule bindings. See `schemas/` in the repository root for the source schemas.
+
+pub mod allowlist;
+pub mod collection;
+pub mod nft;
@@ -4,6 +4,8 @@ use std::fs;
 use std::path::PathBuf;
 use std::str::FromStr;
 
+#[cfg(test)]
+mod schemas;
 #[cfg(test)]
 mod tests;
 
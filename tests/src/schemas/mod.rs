@@ -0,0 +1,8 @@
+//! Generated Molecule bindings. See `schemas/` in the repository root for the source schemas.
+
+#[allow(dead_code)]
+pub mod allowlist;
+#[allow(dead_code)]
+pub mod collection;
+#[allow(dead_code)]
+pub mod nft;